@@ -2,7 +2,7 @@ use crate::*;
 
 /// Represents the body content of an HTTP request/response.
 ///
-/// Can be text, JSON or binary data.
+/// Can be text, JSON, binary data, a URL-encoded form or a multipart form.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum Body {
     /// Text content body.
@@ -11,4 +11,8 @@ pub(crate) enum Body {
     Json(BodyJson),
     /// Binary data body.
     Binary(BodyBinary),
+    /// URL-encoded form body.
+    Form(BodyForm),
+    /// Multipart form body.
+    Multipart(Multipart),
 }
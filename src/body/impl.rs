@@ -30,12 +30,16 @@ impl Display for Body {
                 serde_json::to_string(json).unwrap_or_else(|_| String::from("{}"))
             ),
             Self::Binary(binary) => write!(f, "{:?}", binary),
+            Self::Form(form) => write!(f, "{}", form),
+            Self::Multipart(multipart) => write!(f, "{:?}", multipart),
         }
     }
 }
 
 /// Serializes the Body content.
 ///
+/// Multipart bodies are refused, since their file parts are only read when the request is sent.
+///
 /// # Arguments
 ///
 /// - `S` - The type of the serializer.
@@ -52,6 +56,70 @@ impl Serialize for Body {
             Self::Text(text) => text.serialize(serializer),
             Self::Json(json) => json.serialize(serializer),
             Self::Binary(binary) => binary.serialize(serializer),
+            Self::Form(form) => form.serialize(serializer),
+            Self::Multipart(_) => Err(SerializeError::custom(
+                "multipart bodies cannot be serialized, they are encoded when the request is sent",
+            )),
+        }
+    }
+}
+
+impl Body {
+    /// Gets the Content-Type used when the request does not set one.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - `text/plain; charset=utf-8` for text, `application/json` for JSON,
+    ///   `application/octet-stream` for binary bodies, `application/x-www-form-urlencoded`
+    ///   for forms and `multipart/form-data` with the boundary for multipart forms.
+    pub(crate) fn get_default_content_type(&self) -> String {
+        match self {
+            Self::Text(_) => ContentType::format_content_type_with_charset(TEXT_PLAIN, UTF8),
+            Self::Json(_) => APPLICATION_JSON.to_owned(),
+            Self::Binary(_) => APPLICATION_OCTET_STREAM.to_owned(),
+            Self::Form(_) => FORM_URLENCODED.to_owned(),
+            Self::Multipart(multipart) => multipart.get_content_type(),
         }
     }
+
+    /// Serializes the body into the bytes sent on the wire.
+    ///
+    /// Text, form and binary bodies are sent as given, and multipart forms are encoded in memory.
+    /// JSON bodies follow the Content-Type, so a form or XML Content-Type serializes the
+    /// fields in that format, and any other Content-Type sends JSON.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<&str>` - The Content-Type of the request, if set.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The serialized body, or an error if a multipart file
+    ///   could not be read.
+    pub(crate) fn get_bytes(&self, content_type: Option<&str>) -> Result<Vec<u8>, RequestError> {
+        Ok(match self {
+            Self::Text(text) | Self::Form(text) => text.clone().into_bytes(),
+            Self::Binary(binary) => binary.clone(),
+            Self::Multipart(multipart) => {
+                let mut bytes: Vec<u8> = Vec::with_capacity(multipart.get_content_length());
+                multipart.write_to(&mut bytes)?;
+                bytes
+            }
+            Self::Json(_) => {
+                let content_type: ContentType = content_type
+                    .and_then(|value| value.split(';').next())
+                    .map_or(ContentType::ApplicationJson, |media_type| {
+                        media_type.trim().parse::<ContentType>().unwrap_or_default()
+                    });
+                match content_type {
+                    ContentType::FormUrlEncoded | ContentType::ApplicationXml => {
+                        content_type.get_body_string(self).into_bytes()
+                    }
+                    _ => ContentType::ApplicationJson
+                        .get_body_string(self)
+                        .into_bytes(),
+                }
+            }
+        })
+    }
 }
@@ -1,8 +1,10 @@
 use crate::*;
 
-/// JSON body content represented as a hash map with string keys.
-pub(crate) type BodyJson = HashMapXxHash3_64<String, JsonValue>;
+/// JSON body content represented as any JSON value.
+pub(crate) type BodyJson = JsonValue;
 /// Text body content represented as a string.
 pub(crate) type BodyText = String;
 /// Binary body content represented as a byte vector.
 pub(crate) type BodyBinary = Vec<u8>;
+/// URL-encoded form content represented as an encoded string.
+pub(crate) type BodyForm = String;
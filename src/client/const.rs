@@ -0,0 +1,5 @@
+/// Default maximum number of idle connections kept per host.
+pub(crate) const DEFAULT_MAX_IDLE_PER_HOST: usize = 8;
+
+/// Default time in milliseconds an idle connection is kept before it is closed.
+pub(crate) const DEFAULT_POOL_IDLE_TIMEOUT: u64 = 90_000;
@@ -0,0 +1,9 @@
+use crate::*;
+
+/// Connection handed out by a pool.
+pub(crate) enum PoolCheckout<S> {
+    /// An idle connection and the details recorded when it was opened.
+    Idle(S, ConnectionInfo),
+    /// A slot for a new connection.
+    Open(Box<ConnectionPermit>),
+}
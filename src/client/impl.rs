@@ -0,0 +1,1547 @@
+use crate::*;
+
+/// Provides the default pool limits.
+///
+/// # Returns
+///
+/// - `PoolState` - An empty pool keeping up to `DEFAULT_MAX_IDLE_PER_HOST` idle connections
+///   per host for `DEFAULT_POOL_IDLE_TIMEOUT` milliseconds.
+impl Default for PoolState {
+    fn default() -> Self {
+        Self {
+            max_idle_per_host: DEFAULT_MAX_IDLE_PER_HOST,
+            idle_timeout: Duration::from_millis(DEFAULT_POOL_IDLE_TIMEOUT),
+            sync_connections: HashMap::new(),
+            async_connections: HashMap::new(),
+            http2_connections: HashMap::new(),
+        }
+    }
+}
+
+impl PoolState {
+    /// Counts the idle connections of both kinds.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of idle connections.
+    fn idle_count(&self) -> usize {
+        self.sync_connections
+            .values()
+            .map(VecDeque::len)
+            .sum::<usize>()
+            + self
+                .async_connections
+                .values()
+                .map(VecDeque::len)
+                .sum::<usize>()
+    }
+
+    /// Closes every connection that has been idle for longer than the idle timeout.
+    fn evict_expired(&mut self) {
+        let idle_timeout: Duration = self.idle_timeout;
+        Self::evict_expired_from(&mut self.sync_connections, idle_timeout);
+        Self::evict_expired_from(&mut self.async_connections, idle_timeout);
+    }
+
+    /// Closes the expired connections of one map and drops keys left without connections.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>` - The idle connections.
+    /// - `Duration` - The idle timeout.
+    fn evict_expired_from<S>(
+        connections: &mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>,
+        idle_timeout: Duration,
+    ) {
+        connections.retain(|_, idle| {
+            idle.retain(|connection| connection.idle_since.elapsed() < idle_timeout);
+            !idle.is_empty()
+        });
+    }
+
+    /// Takes the most recently used idle connection for a key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>` - The idle connections.
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(S, ConnectionInfo)>` - The connection and its details, if one is idle.
+    fn take_from<S>(
+        connections: &mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>,
+        key: &PoolKey,
+    ) -> Option<(S, ConnectionInfo)> {
+        let idle: &mut VecDeque<IdleConnection<S>> = connections.get_mut(key)?;
+        let connection: Option<(S, ConnectionInfo)> = idle
+            .pop_back()
+            .map(|connection| (connection.stream, connection.connection_info));
+        if idle.is_empty() {
+            connections.remove(key);
+        }
+        connection
+    }
+
+    /// Closes the oldest idle connection for a key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>` - The idle connections.
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether a connection was closed.
+    fn close_oldest<S>(
+        connections: &mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>,
+        key: &PoolKey,
+    ) -> bool {
+        let Some(idle) = connections.get_mut(key) else {
+            return false;
+        };
+        let closed: bool = idle.pop_front().is_some();
+        if idle.is_empty() {
+            connections.remove(key);
+        }
+        closed
+    }
+
+    /// Returns a connection to the pool, closing the oldest ones above the per-host limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>` - The idle connections.
+    /// - `PoolKey` - The key of the connection.
+    /// - `S` - The connection.
+    /// - `ConnectionInfo` - The details of the connection.
+    /// - `usize` - The maximum number of idle connections per key.
+    fn put_into<S>(
+        connections: &mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>,
+        key: PoolKey,
+        stream: S,
+        connection_info: ConnectionInfo,
+        max_idle_per_host: usize,
+    ) {
+        if max_idle_per_host == 0 {
+            return;
+        }
+        let idle: &mut VecDeque<IdleConnection<S>> = connections.entry(key).or_default();
+        idle.push_back(IdleConnection {
+            stream,
+            connection_info,
+            idle_since: Instant::now(),
+        });
+        while idle.len() > max_idle_per_host {
+            idle.pop_front();
+        }
+    }
+}
+
+impl ConnectionLimits {
+    /// Wakes every request waiting for a connection of any key.
+    fn notify(&self) {
+        self.state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .generation += 1;
+        self.released.notify_all();
+        self.released_async.notify_waiters();
+    }
+}
+
+/// Releases the slot of a closed connection.
+impl Drop for ConnectionPermit {
+    fn drop(&mut self) {
+        {
+            let mut state: MutexGuard<'_, LimitState> = self
+                .limits
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if let Some(open) = state.open.get_mut(&self.key) {
+                *open = open.saturating_sub(1);
+                if *open == 0 {
+                    state.open.remove(&self.key);
+                }
+            }
+        }
+        self.limits.notify();
+    }
+}
+
+impl<S: Read> Read for PooledStream<S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.stream.read(buf)
+    }
+}
+
+impl<S: Write> Write for PooledStream<S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.stream.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.stream.flush()
+    }
+}
+
+impl ReadWrite for PooledStream<BoxReadWrite> {
+    fn get_tcp_stream(&self) -> &TcpStream {
+        self.stream.get_tcp_stream()
+    }
+}
+
+impl<S: AsyncRead + Unpin> AsyncRead for PooledStream<S> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_read(cx, buf)
+    }
+}
+
+impl<S: AsyncWrite + Unpin> AsyncWrite for PooledStream<S> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.stream).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+impl SyncRuntime {
+    /// Runs a future on the runtime and blocks the current thread until it completes.
+    ///
+    /// Unlike `Runtime::block_on`, this may be called from within another runtime.
+    ///
+    /// # Arguments
+    ///
+    /// - `F` - The future.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<F::Output, RequestError>` - The output of the future, or an error if the
+    ///   runtime could not be started or the future panicked.
+    pub(crate) fn run<F>(&self, future: F) -> Result<F::Output, RequestError>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let (sender, receiver): (Sender<F::Output>, Receiver<F::Output>) = channel();
+        {
+            let mut runtime: MutexGuard<'_, Option<Runtime>> = self
+                .runtime
+                .lock()
+                .map_err(|err| RequestError::Unknown(err.to_string()))?;
+            if runtime.is_none() {
+                *runtime = Some(
+                    RuntimeBuilder::new_multi_thread()
+                        .worker_threads(1)
+                        .enable_all()
+                        .build()
+                        .map_err(|err| RequestError::Request(err.to_string()))?,
+                );
+            }
+            if let Some(runtime) = runtime.as_ref() {
+                runtime.spawn(async move {
+                    let _ = sender.send(future.await);
+                });
+            }
+        }
+        receiver
+            .recv()
+            .map_err(|_| RequestError::Request("request task was aborted".to_string()))
+    }
+}
+
+/// Shuts the runtime down without waiting for its tasks, so it can be dropped anywhere.
+impl Drop for SyncRuntime {
+    fn drop(&mut self) {
+        if let Some(runtime) = self
+            .runtime
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+        {
+            runtime.shutdown_background();
+        }
+    }
+}
+
+impl Debug for ConnectionPool {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let idle_count: usize = self.state.lock().map_or(0, |state| state.idle_count());
+        f.debug_struct("ConnectionPool")
+            .field("idle_count", &idle_count)
+            .finish()
+    }
+}
+
+impl PartialEq for ConnectionPool {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+impl Eq for ConnectionPool {}
+
+impl ConnectionPool {
+    /// Takes a slot for a new connection if the key is below the connection limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut LimitState` - The locked counts.
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ConnectionPermit>` - The slot, or `None` at the limit.
+    fn try_open(&self, state: &mut LimitState, key: &PoolKey) -> Option<ConnectionPermit> {
+        let open: usize = state.open.get(key).copied().unwrap_or_default();
+        if state
+            .max_connections_per_host
+            .is_some_and(|max_connections| open >= max_connections)
+        {
+            return None;
+        }
+        state.open.insert(key.clone(), open + 1);
+        Some(ConnectionPermit {
+            limits: self.limits.clone(),
+            key: key.clone(),
+        })
+    }
+
+    /// Gets an idle synchronous connection for a key, or a slot to open one, waiting while the
+    /// key is at its connection limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PoolKey` - The key of the request.
+    /// - `Duration` - How long to wait for a connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<PoolCheckout<BoxReadWrite>>` - The connection or slot, or `None` if none became
+    ///   available in time.
+    pub(crate) fn checkout_sync(
+        &self,
+        key: &PoolKey,
+        timeout: Duration,
+    ) -> Option<PoolCheckout<BoxReadWrite>> {
+        let deadline: Instant = Instant::now() + timeout;
+        loop {
+            let generation: u64 = self
+                .limits
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .generation;
+            if let Some((stream, connection_info)) = self.take_sync(key) {
+                return Some(PoolCheckout::Idle(stream, connection_info));
+            }
+            if let Some(permit) = self.try_open(
+                &mut self
+                    .limits
+                    .state
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner),
+                key,
+            ) {
+                return Some(PoolCheckout::Open(Box::new(permit)));
+            }
+            if self.close_idle(key, |state| &mut state.async_connections) {
+                continue;
+            }
+            let state: MutexGuard<'_, LimitState> = self
+                .limits
+                .state
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if state.generation != generation {
+                continue;
+            }
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return None;
+            }
+            let _ = self
+                .limits
+                .released
+                .wait_timeout(state, remaining)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    /// Gets an idle asynchronous connection for a key, or a slot to open one, waiting while the
+    /// key is at its connection limit.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `PoolCheckout<BoxAsyncReadWrite>` - The connection or slot.
+    pub(crate) async fn checkout_async(&self, key: &PoolKey) -> PoolCheckout<BoxAsyncReadWrite> {
+        loop {
+            let released: Notified<'_> = self.limits.released_async.notified();
+            if let Some((stream, connection_info)) = self.take_async(key) {
+                return PoolCheckout::Idle(stream, connection_info);
+            }
+            if let Some(permit) = self.try_open(
+                &mut self
+                    .limits
+                    .state
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner),
+                key,
+            ) {
+                return PoolCheckout::Open(Box::new(permit));
+            }
+            if self.close_idle(key, |state| &mut state.sync_connections) {
+                continue;
+            }
+            released.await;
+        }
+    }
+
+    /// Closes an idle connection of the other kind for a key, so a key at its connection limit
+    /// is not held by connections the request cannot use.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PoolKey` - The key of the request.
+    /// - `F` - Selects the idle connections of the other kind.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether a connection was closed.
+    fn close_idle<S, F>(&self, key: &PoolKey, connections: F) -> bool
+    where
+        F: FnOnce(&mut PoolState) -> &mut HashMap<PoolKey, VecDeque<IdleConnection<S>>>,
+    {
+        match self.state.lock() {
+            Ok(mut state) => PoolState::close_oldest(connections(&mut state), key),
+            Err(_) => false,
+        }
+    }
+
+    /// Takes an idle synchronous connection for a key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(BoxReadWrite, ConnectionInfo)>` - The connection and its details, if one is
+    ///   idle and has not expired.
+    pub(crate) fn take_sync(&self, key: &PoolKey) -> Option<(BoxReadWrite, ConnectionInfo)> {
+        let mut state: MutexGuard<'_, PoolState> = self.state.lock().ok()?;
+        state.evict_expired();
+        PoolState::take_from(&mut state.sync_connections, key)
+    }
+
+    /// Returns a synchronous connection to the pool.
+    ///
+    /// # Arguments
+    ///
+    /// - `PoolKey` - The key of the connection.
+    /// - `BoxReadWrite` - The connection.
+    /// - `ConnectionInfo` - The details of the connection.
+    pub(crate) fn put_sync(
+        &self,
+        key: PoolKey,
+        stream: BoxReadWrite,
+        connection_info: ConnectionInfo,
+    ) {
+        if let Ok(mut state) = self.state.lock() {
+            state.evict_expired();
+            let max_idle_per_host: usize = state.max_idle_per_host;
+            PoolState::put_into(
+                &mut state.sync_connections,
+                key,
+                stream,
+                connection_info,
+                max_idle_per_host,
+            );
+        }
+        self.limits.notify();
+    }
+
+    /// Takes an idle asynchronous connection for a key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(BoxAsyncReadWrite, ConnectionInfo)>` - The connection and its details, if one
+    ///   is idle and has not expired.
+    pub(crate) fn take_async(&self, key: &PoolKey) -> Option<(BoxAsyncReadWrite, ConnectionInfo)> {
+        let mut state: MutexGuard<'_, PoolState> = self.state.lock().ok()?;
+        state.evict_expired();
+        PoolState::take_from(&mut state.async_connections, key)
+    }
+
+    /// Returns an asynchronous connection to the pool.
+    ///
+    /// # Arguments
+    ///
+    /// - `PoolKey` - The key of the connection.
+    /// - `BoxAsyncReadWrite` - The connection.
+    /// - `ConnectionInfo` - The details of the connection.
+    pub(crate) fn put_async(
+        &self,
+        key: PoolKey,
+        stream: BoxAsyncReadWrite,
+        connection_info: ConnectionInfo,
+    ) {
+        if let Ok(mut state) = self.state.lock() {
+            state.evict_expired();
+            let max_idle_per_host: usize = state.max_idle_per_host;
+            PoolState::put_into(
+                &mut state.async_connections,
+                key,
+                stream,
+                connection_info,
+                max_idle_per_host,
+            );
+        }
+        self.limits.notify();
+    }
+
+    /// Gets the slot holding the shared HTTP/2 connection for a key.
+    ///
+    /// # Arguments
+    ///
+    /// - `&PoolKey` - The key of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Http2Slot` - The slot, locked while a connection is opened.
+    pub(crate) fn get_http2_slot(&self, key: &PoolKey) -> Http2Slot {
+        match self.state.lock() {
+            Ok(mut state) => state
+                .http2_connections
+                .entry(key.clone())
+                .or_default()
+                .clone(),
+            Err(_) => Arc::default(),
+        }
+    }
+}
+
+impl Client {
+    /// Creates a client with an empty connection pool.
+    ///
+    /// # Returns
+    ///
+    /// - `Client` - A new client.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the maximum number of idle connections kept per host.
+    ///
+    /// Connections above the limit are closed when they are returned to the pool, oldest first.
+    /// A limit of zero disables reuse. Connections in use are not limited by it, see
+    /// `max_connections_per_host`.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of idle connections per host.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn max_idle_per_host(&mut self, max_idle_per_host: usize) -> &mut Self {
+        if let Ok(mut state) = self.pool.state.lock() {
+            state.max_idle_per_host = max_idle_per_host;
+            for idle in state.sync_connections.values_mut() {
+                idle.drain(..idle.len().saturating_sub(max_idle_per_host));
+            }
+            for idle in state.async_connections.values_mut() {
+                idle.drain(..idle.len().saturating_sub(max_idle_per_host));
+            }
+            state.sync_connections.retain(|_, idle| !idle.is_empty());
+            state.async_connections.retain(|_, idle| !idle.is_empty());
+        }
+        self
+    }
+
+    /// Limits the number of open connections per host, idle ones included.
+    ///
+    /// A request needing a new connection while its host is at the limit waits for a
+    /// connection to be returned or closed, up to the connect timeout. Requests with different
+    /// TLS or resolution settings count separately. HTTP/2 requests share a single connection
+    /// per host that is not counted. The limit is at least one.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of open connections per host.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn max_connections_per_host(&mut self, max_connections_per_host: usize) -> &mut Self {
+        self.pool
+            .limits
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .max_connections_per_host = Some(max_connections_per_host.max(1));
+        self.pool.limits.notify();
+        self
+    }
+
+    /// Sets how long a connection may stay idle in the pool before it is closed.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The idle timeout in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn idle_timeout(&mut self, idle_timeout: u64) -> &mut Self {
+        if let Ok(mut state) = self.pool.state.lock() {
+            state.idle_timeout = Duration::from_millis(idle_timeout);
+            state.evict_expired();
+        }
+        self
+    }
+
+    /// Gets the number of idle HTTP/1.1 connections currently kept by the pool.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of idle connections.
+    pub fn idle_connections(&self) -> usize {
+        self.pool.state.lock().map_or(0, |mut state| {
+            state.evict_expired();
+            state.idle_count()
+        })
+    }
+
+    /// Closes every idle connection of the pool.
+    pub fn clear_idle_connections(&self) {
+        if let Ok(mut state) = self.pool.state.lock() {
+            state.sync_connections.clear();
+            state.async_connections.clear();
+            state.http2_connections.clear();
+        }
+    }
+
+    /// Sets the base URL relative request URLs are resolved against.
+    ///
+    /// References are resolved as in RFC 3986, so the base URL should end with `/` for
+    /// relative paths to be appended to its last segment. Absolute URLs are used unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The base URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn base_url(&mut self, base_url: &str) -> &mut Self {
+        self.base_url = Some(base_url.to_owned());
+        self
+    }
+
+    /// Sets a header sent with every request, replacing any earlier value.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The header name (must implement ToString).
+    /// - `V` - The header value (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn header<K: ToString, V: ToString>(&mut self, key: K, value: V) -> &mut Self {
+        self.header.set(key.to_string(), value.to_string());
+        self
+    }
+
+    /// Sets several headers sent with every request.
+    ///
+    /// # Arguments
+    ///
+    /// - `HashMapXxHash3_64<K, V>` - The headers.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn headers<K, V>(&mut self, header: HashMapXxHash3_64<K, V>) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        for (key, value) in header {
+            self.header.set(key.to_string(), value.to_string());
+        }
+        self
+    }
+
+    /// Removes a default header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn remove_header(&mut self, key: &str) -> &mut Self {
+        self.header.remove(key);
+        self
+    }
+
+    /// Sets the default timeout in milliseconds.
+    ///
+    /// It applies to connecting, each write and each read, unless those have their own timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
+        self.config.timeout = timeout;
+        self
+    }
+
+    /// Sets the default timeout in milliseconds for resolving the host and connecting, TLS
+    /// handshake and proxy negotiation included.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn connect_timeout(&mut self, timeout: u64) -> &mut Self {
+        self.config.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how long in milliseconds each read of a response may wait for data by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn read_timeout(&mut self, timeout: u64) -> &mut Self {
+        self.config.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the default timeout in milliseconds for writing a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn write_timeout(&mut self, timeout: u64) -> &mut Self {
+        self.config.write_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the default deadline in milliseconds for each request, redirects included.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The deadline.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn total_timeout(&mut self, timeout: u64) -> &mut Self {
+        self.config.total_timeout = Some(timeout);
+        self
+    }
+
+    /// Aborts each request whose response arrives slower than a minimum speed by default.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The minimum speed in bytes per second.
+    /// - `u64` - The time in milliseconds the speed is averaged over.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn low_speed_limit(&mut self, bytes_per_second: u64, time: u64) -> &mut Self {
+        self.config.low_speed_limit = Some(LowSpeedLimit::new(bytes_per_second, time));
+        self
+    }
+
+    /// Follows redirects by default.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn redirect(&mut self) -> &mut Self {
+        self.config.redirect = true;
+        self
+    }
+
+    /// Does not follow redirects by default.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn unredirect(&mut self) -> &mut Self {
+        self.config.redirect = false;
+        self
+    }
+
+    /// Sets the default maximum number of redirects followed.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of redirects.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn max_redirect_times(&mut self, num: usize) -> &mut Self {
+        self.config.max_redirect_times = num;
+        self
+    }
+
+    /// Sets the default read buffer size in bytes.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn buffer(&mut self, buffer: usize) -> &mut Self {
+        self.config.buffer = buffer;
+        self
+    }
+
+    /// Decodes compressed response bodies by default.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn decode(&mut self) -> &mut Self {
+        self.config.decode = true;
+        self
+    }
+
+    /// Returns response bodies as received by default.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn undecode(&mut self) -> &mut Self {
+        self.config.decode = false;
+        self
+    }
+
+    /// Uses HTTP/1.1 by default.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn http1_1_only(&mut self) -> &mut Self {
+        self.config.http_version = HttpVersion::HTTP1_1;
+        self
+    }
+
+    /// Uses HTTP/2 by default.
+    ///
+    /// Concurrent requests to the same host are multiplexed over one shared connection.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn http2_only(&mut self) -> &mut Self {
+        self.config.http_version = HttpVersion::HTTP2;
+        self
+    }
+
+    /// Attaches a cookie jar to every request.
+    ///
+    /// `Secure` cookies received over `http` are dropped, so they are only stored from
+    /// `https` responses.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CookieJar` - The cookie jar.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn cookie_jar(&mut self, cookie_jar: &CookieJar) -> &mut Self {
+        self.config.cookie_jar = Some(cookie_jar.clone());
+        self
+    }
+
+    /// Sends every request through an HTTP proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        self.proxy(ProxyType::Http, host, port, None)
+    }
+
+    /// Sends every request through an HTTPS proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn https_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        self.proxy(ProxyType::Https, host, port, None)
+    }
+
+    /// Sends every request through a SOCKS5 proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn socks5_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        self.proxy(ProxyType::Socks5, host, port, None)
+    }
+
+    /// Sends every request through an HTTP proxy with authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    /// - `&str` - The username.
+    /// - `&str` - The password.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn http_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        self.proxy(ProxyType::Http, host, port, Some((username, password)))
+    }
+
+    /// Sends every request through an HTTPS proxy with authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    /// - `&str` - The username.
+    /// - `&str` - The password.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn https_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        self.proxy(ProxyType::Https, host, port, Some((username, password)))
+    }
+
+    /// Sends every request through a SOCKS5 proxy with authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    /// - `&str` - The username.
+    /// - `&str` - The password.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn socks5_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        self.proxy(ProxyType::Socks5, host, port, Some((username, password)))
+    }
+
+    /// Trusts the CA certificates of a PEM bundle for every request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The PEM data, holding one or more certificates.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn root_cert_pem(&mut self, pem: &[u8]) -> &mut Self {
+        self.config.tls.detach_cache().add_root_cert_pem(pem);
+        self
+    }
+
+    /// Trusts a DER-encoded CA certificate for every request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The DER data of the certificate.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn root_cert_der(&mut self, der: &[u8]) -> &mut Self {
+        self.config.tls.detach_cache().add_root_cert_der(der);
+        self
+    }
+
+    /// Trusts the CA certificates of a PEM bundle or DER file for every request.
+    ///
+    /// # Arguments
+    ///
+    /// - `P` - The path of the file, read immediately.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn root_cert_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.config
+            .tls
+            .detach_cache()
+            .add_root_cert_file(path.as_ref());
+        self
+    }
+
+    /// Trusts the certificates of the operating system trust store for every request.
+    ///
+    /// The store is read once, when this method is called. Combine with `without_webpki_roots`
+    /// to trust the system store only.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn system_root_certs(&mut self) -> &mut Self {
+        self.config.tls.detach_cache().add_system_root_certs();
+        self
+    }
+
+    /// Stops trusting the bundled webpki roots, so only the added root certificates are used.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn without_webpki_roots(&mut self) -> &mut Self {
+        self.config.tls.detach_cache().webpki_roots = false;
+        self
+    }
+
+    /// Presents a client certificate for mutual TLS on every request, from PEM data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The PEM certificate chain, leaf first.
+    /// - `&[u8]` - The PEM private key (PKCS#8, PKCS#1 or SEC1).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn client_cert_pem(&mut self, cert_chain: &[u8], key: &[u8]) -> &mut Self {
+        self.config
+            .tls
+            .detach_cache()
+            .set_client_cert_pem(cert_chain, key);
+        self
+    }
+
+    /// Presents a client certificate for mutual TLS on every request, from DER data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The DER certificate.
+    /// - `&[u8]` - The DER private key (PKCS#8, PKCS#1 or SEC1).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn client_cert_der(&mut self, cert: &[u8], key: &[u8]) -> &mut Self {
+        self.config
+            .tls
+            .detach_cache()
+            .set_client_cert_der(cert, key);
+        self
+    }
+
+    /// Presents a client certificate for mutual TLS on every request, from PEM or DER files.
+    ///
+    /// # Arguments
+    ///
+    /// - `P` - The path of the certificate chain, read immediately.
+    /// - `P` - The path of the private key, read immediately.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn client_cert_file<P: AsRef<Path>>(&mut self, cert_path: P, key_path: P) -> &mut Self {
+        self.config
+            .tls
+            .detach_cache()
+            .set_client_cert_file(cert_path.as_ref(), key_path.as_ref());
+        self
+    }
+
+    /// Accepts any server certificate, including expired, self-signed or mismatched ones.
+    ///
+    /// This disables the protection TLS offers against impersonation and is meant for throwaway
+    /// test environments only. Pins still apply, and HTTPS proxies are still verified against
+    /// the trusted roots.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn danger_accept_invalid_certs(&mut self) -> &mut Self {
+        self.config.tls.detach_cache().verification = CertVerification::AcceptInvalid;
+        self
+    }
+
+    /// Verifies the certificates of the target with a custom policy instead of the trusted roots.
+    ///
+    /// HTTPS proxies are still verified against the trusted roots.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn ServerCertVerifier>` - The verifier, implementing
+    ///   `rustls::client::danger::ServerCertVerifier`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn cert_verifier(&mut self, verifier: Arc<dyn ServerCertVerifier>) -> &mut Self {
+        self.config.tls.detach_cache().verification = CertVerification::Custom(verifier);
+        self
+    }
+
+    /// Pins the SHA-256 hash of a subject public key info (SPKI).
+    ///
+    /// Once a pin is added, the leaf certificate of the target must have a pinned public key or
+    /// be a pinned certificate after the chain passed verification, otherwise the connection
+    /// fails with a `CertificatePinError`. May be called several times to allow backup
+    /// keys.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The base64 hash, optionally prefixed with `sha256//` as curl accepts it.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn pin_spki_sha256(&mut self, pin: &str) -> &mut Self {
+        self.config.tls.detach_cache().add_spki_pin(pin);
+        self
+    }
+
+    /// Pins the SHA-256 hash of the DER encoded leaf certificate of the server.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The base64 hash, optionally prefixed with `sha256//`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn pin_cert_sha256(&mut self, pin: &str) -> &mut Self {
+        self.config.tls.detach_cache().add_cert_pin(pin);
+        self
+    }
+
+    /// Restricts the TLS protocol versions that may be negotiated.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[&'static SupportedProtocolVersion]` - The allowed versions, such as
+    ///   `rustls::version::TLS13`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn tls_versions(&mut self, versions: &[&'static SupportedProtocolVersion]) -> &mut Self {
+        self.config.tls.detach_cache().versions = versions.to_vec();
+        self
+    }
+
+    /// Only negotiates TLS 1.3.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn tls1_3_only(&mut self) -> &mut Self {
+        self.config.tls.detach_cache().versions = vec![&TLS13];
+        self
+    }
+
+    /// Restricts the cipher suites offered to the server.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[SupportedCipherSuite]` - The allowed suites in order of preference, such as
+    ///   `rustls::crypto::aws_lc_rs::cipher_suite::TLS13_AES_256_GCM_SHA384`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn tls_cipher_suites(&mut self, cipher_suites: &[SupportedCipherSuite]) -> &mut Self {
+        self.config.tls.detach_cache().cipher_suites = cipher_suites.to_vec();
+        self
+    }
+
+    /// Stops sending the server name in the SNI extension.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn without_tls_sni(&mut self) -> &mut Self {
+        self.config.tls.detach_cache().sni = false;
+        self
+    }
+
+    /// Overrides the name sent in SNI and verified against the certificate of the target.
+    ///
+    /// Useful when connecting by IP address or through an alias of the host. HTTPS proxies
+    /// keep their own name.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The DNS name or IP address.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn tls_server_name(&mut self, name: &str) -> &mut Self {
+        self.config.tls.detach_cache().set_server_name(name);
+        self
+    }
+
+    /// Disables TLS session resumption, so every connection performs a full handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn without_tls_session_resumption(&mut self) -> &mut Self {
+        self.config.tls.detach_cache().session_resumption = false;
+        self
+    }
+
+    /// Appends the TLS secrets of every request to a key log file, so captures can be decoded.
+    ///
+    /// Without this, secrets are logged to the file named by `SSLKEYLOGFILE` when it is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `P` - The path of the key log file, created if needed.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn tls_key_log_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.config
+            .tls
+            .detach_cache()
+            .set_key_log_file(path.as_ref());
+        self
+    }
+
+    /// Hands the TLS secrets of every request to a custom key logger.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn KeyLog>` - The key logger.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn tls_key_log(&mut self, key_log: Arc<dyn KeyLog>) -> &mut Self {
+        self.config.tls.detach_cache().key_log = Some(TlsKeyLog(key_log));
+        self
+    }
+
+    /// Connects every request to an address instead of resolving a host and port, like curl's
+    /// `--resolve`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host name, matched case-insensitively.
+    /// - `u16` - The port.
+    /// - `IpAddr` - The address to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn resolve(&mut self, host: &str, port: u16, addr: IpAddr) -> &mut Self {
+        StaticResolver::insert_entry(&mut self.config.dns.detach().overrides, host, port, addr);
+        self
+    }
+
+    /// Resolves hosts with a custom resolver by default, replacing the system resolver and any
+    /// DNS cache.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn Resolver>` - The resolver.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn resolver(&mut self, resolver: Arc<dyn Resolver>) -> &mut Self {
+        self.config.dns.detach().resolver = SharedResolver(resolver);
+        self
+    }
+
+    /// Reuses the answers of the resolver set so far across requests for a fixed time.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - How long an answer is reused, in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    pub fn dns_cache(&mut self, ttl: u64) -> &mut Self {
+        self.config
+            .dns
+            .detach()
+            .set_cache_ttl(Duration::from_millis(ttl));
+        self
+    }
+
+    /// Sets the default proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `ProxyType` - The proxy type.
+    /// - `&str` - The proxy host.
+    /// - `u16` - The proxy port.
+    /// - `Option<(&str, &str)>` - The username and password, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Client` - The client for method chaining.
+    fn proxy(
+        &mut self,
+        proxy_type: ProxyType,
+        host: &str,
+        port: u16,
+        credentials: Option<(&str, &str)>,
+    ) -> &mut Self {
+        self.config.proxy = Some(ProxyConfig {
+            proxy_type,
+            host: host.to_string(),
+            port,
+            username: credentials.map(|(username, _)| username.to_string()),
+            password: credentials.map(|(_, password)| password.to_string()),
+        });
+        self
+    }
+
+    /// Resolves a request URL against the base URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL, absolute or relative.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The URL the request is sent to.
+    pub fn resolve_url(&self, url: &str) -> String {
+        match &self.base_url {
+            Some(base_url) => resolve_url(base_url, url),
+            None => url.to_owned(),
+        }
+    }
+
+    /// Creates a request builder pre-filled with the defaults of this client.
+    ///
+    /// The builder carries the default headers and configuration and sends its requests
+    /// through the client's connection pool. Settings made on the builder override the defaults.
+    /// Relative URLs later passed to its URL setters are resolved against the base URL.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - A builder using the defaults and connection pool of the client.
+    pub fn request(&self) -> RequestBuilder {
+        let mut config: Config = self.config.clone();
+        config.pool = Some(self.pool.clone());
+        let mut builder: RequestBuilder = RequestBuilder::new();
+        builder.http_request.header = Arc::new(self.header.clone());
+        builder.http_request.config = Arc::new(RwLock::new(config));
+        builder.base_url = self.base_url.clone();
+        builder
+    }
+
+    /// Creates a request builder for a GET request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn get(&self, url: &str) -> RequestBuilder {
+        self.method(Method::GET, url)
+    }
+
+    /// Creates a request builder for a POST request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn post(&self, url: &str) -> RequestBuilder {
+        self.method(Method::POST, url)
+    }
+
+    /// Creates a request builder for a PUT request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn put(&self, url: &str) -> RequestBuilder {
+        self.method(Method::PUT, url)
+    }
+
+    /// Creates a request builder for a PATCH request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn patch(&self, url: &str) -> RequestBuilder {
+        self.method(Method::PATCH, url)
+    }
+
+    /// Creates a request builder for a DELETE request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn delete(&self, url: &str) -> RequestBuilder {
+        self.method(Method::DELETE, url)
+    }
+
+    /// Creates a request builder for a HEAD request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn head(&self, url: &str) -> RequestBuilder {
+        self.method(Method::HEAD, url)
+    }
+
+    /// Creates a request builder for an OPTIONS request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn options(&self, url: &str) -> RequestBuilder {
+        self.method(Method::OPTIONS, url)
+    }
+
+    /// Creates a request builder for a TRACE request sent through this client.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn trace(&self, url: &str) -> RequestBuilder {
+        self.method(Method::TRACE, url)
+    }
+
+    /// Creates a request builder for a request with the given method sent through this client.
+    ///
+    /// Relative URLs are resolved against the base URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The request method.
+    /// - `&str` - The URL for the request.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestBuilder` - The request builder.
+    pub fn method(&self, method: Method, url: &str) -> RequestBuilder {
+        let mut builder: RequestBuilder = self.request();
+        builder.method(method, url);
+        builder
+    }
+}
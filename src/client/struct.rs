@@ -0,0 +1,127 @@
+use crate::*;
+
+/// A long-lived HTTP client that reuses keep-alive connections.
+///
+/// Requests built from a client return their connection to a shared pool once the
+/// response has been read completely, so later requests to the same host skip the
+/// TCP and TLS handshakes. The client also holds a base URL, default headers and default
+/// configuration that pre-fill every request builder it creates. Cloning a client shares
+/// its pool.
+#[derive(Debug, Clone, Default)]
+pub struct Client {
+    /// Pool of idle connections shared by all requests of the client.
+    pub(crate) pool: ConnectionPool,
+    /// Base URL relative request URLs are resolved against.
+    pub(crate) base_url: Option<String>,
+    /// Headers sent with every request.
+    pub(crate) header: OrderedHeaders,
+    /// Configuration every request starts from.
+    pub(crate) config: Config,
+}
+
+/// Shared pool of idle keep-alive connections.
+#[derive(Clone, Default)]
+pub(crate) struct ConnectionPool {
+    /// Idle connections and pool limits.
+    pub(crate) state: Arc<Mutex<PoolState>>,
+    /// Open connections per key, limited separately so a closing connection never waits for
+    /// the idle connections.
+    pub(crate) limits: Arc<ConnectionLimits>,
+    /// Runtime driving the HTTP/2 connections of synchronous requests.
+    pub(crate) runtime: Arc<SyncRuntime>,
+}
+
+/// Runtime started on first use that runs asynchronous work for synchronous requests.
+///
+/// Background tasks such as HTTP/2 connection drivers keep running for as long as the
+/// runtime lives, and dropping it never blocks.
+#[derive(Debug, Default)]
+pub(crate) struct SyncRuntime {
+    /// The runtime, once started.
+    pub(crate) runtime: Mutex<Option<Runtime>>,
+}
+
+/// Open connections per key and the limit they are held to.
+#[derive(Debug, Default)]
+pub(crate) struct ConnectionLimits {
+    /// Counts and limit.
+    pub(crate) state: Mutex<LimitState>,
+    /// Wakes synchronous requests waiting for a connection.
+    pub(crate) released: Condvar,
+    /// Wakes asynchronous requests waiting for a connection.
+    pub(crate) released_async: Notify,
+}
+
+/// Counts of open connections.
+#[derive(Debug, Default)]
+pub(crate) struct LimitState {
+    /// Maximum number of open connections per key, idle ones included, or `None` for no limit.
+    pub(crate) max_connections_per_host: Option<usize>,
+    /// Open connections per key.
+    pub(crate) open: HashMap<PoolKey, usize>,
+    /// Incremented whenever a connection is released or returned, so a waiting request notices
+    /// changes that happened before it started waiting.
+    pub(crate) generation: u64,
+}
+
+/// Slot of an open connection, released when the connection is closed.
+#[derive(Debug)]
+pub(crate) struct ConnectionPermit {
+    /// Limits the slot was taken from.
+    pub(crate) limits: Arc<ConnectionLimits>,
+    /// Key of the connection.
+    pub(crate) key: PoolKey,
+}
+
+/// Connection opened for a pool, holding its slot for as long as it stays open.
+pub(crate) struct PooledStream<S> {
+    /// The open stream.
+    pub(crate) stream: S,
+    /// The slot of the connection, released when the stream is dropped.
+    pub(crate) _permit: ConnectionPermit,
+}
+
+/// Idle connections and limits of a connection pool.
+pub(crate) struct PoolState {
+    /// Maximum number of idle connections kept per key.
+    pub(crate) max_idle_per_host: usize,
+    /// Time after which an idle connection is closed.
+    pub(crate) idle_timeout: Duration,
+    /// Idle connections used by synchronous requests.
+    pub(crate) sync_connections: HashMap<PoolKey, VecDeque<IdleConnection<BoxReadWrite>>>,
+    /// Idle connections used by asynchronous requests.
+    pub(crate) async_connections: HashMap<PoolKey, VecDeque<IdleConnection<BoxAsyncReadWrite>>>,
+    /// Shared HTTP/2 connections, each multiplexing the concurrent requests to one key.
+    pub(crate) http2_connections: HashMap<PoolKey, Http2Slot>,
+}
+
+/// Identifies the connections that can serve a request.
+///
+/// Besides the target and proxy, a connection is only reused by requests with the same TLS
+/// policy and resolution settings, so it was verified, authenticated and resolved the way the
+/// request expects.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct PoolKey {
+    /// URL scheme.
+    pub(crate) protocol: String,
+    /// Target host.
+    pub(crate) host: String,
+    /// Target port.
+    pub(crate) port: u16,
+    /// Proxy the connection is tunnelled through, if any.
+    pub(crate) proxy: Option<ProxyConfig>,
+    /// Id of the TLS settings the connection was opened with.
+    pub(crate) tls_id: u64,
+    /// Id of the resolution settings the address of the connection was looked up with.
+    pub(crate) dns_id: u64,
+}
+
+/// A connection waiting in the pool.
+pub(crate) struct IdleConnection<S> {
+    /// The open stream.
+    pub(crate) stream: S,
+    /// Details recorded when the connection was opened.
+    pub(crate) connection_info: ConnectionInfo,
+    /// When the connection was returned to the pool.
+    pub(crate) idle_since: Instant,
+}
@@ -0,0 +1,4 @@
+use crate::*;
+
+/// Shared HTTP/2 connection of a pool key, with the details recorded when it was opened.
+pub(crate) type Http2Slot = Arc<AsyncMutex<Option<(SendRequest<Bytes>, ConnectionInfo)>>>;
@@ -0,0 +1,109 @@
+use crate::*;
+
+impl OrderedHeaders {
+    /// Finds the position of a header, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<usize>` - The index of the header entry, if present.
+    fn position(&self, name: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(key, _)| key.eq_ignore_ascii_case(name))
+    }
+
+    /// Checks whether a header is present, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the header is present.
+    pub(crate) fn contains_key(&self, name: &str) -> bool {
+        self.position(name).is_some()
+    }
+
+    /// Gets the values of a header, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&RequestHeadersValue>` - The header values, if present.
+    pub(crate) fn get(&self, name: &str) -> Option<&RequestHeadersValue> {
+        self.position(name).map(|index| &self.entries[index].1)
+    }
+
+    /// Gets the values of a header for modification, ignoring case.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&mut RequestHeadersValue>` - The header values, if present.
+    pub(crate) fn get_mut(&mut self, name: &str) -> Option<&mut RequestHeadersValue> {
+        self.position(name)
+            .map(move |index| &mut self.entries[index].1)
+    }
+
+    /// Sets a header, replacing all existing values while keeping its position.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The header name.
+    /// - `String` - The header value.
+    pub(crate) fn set(&mut self, name: String, value: String) {
+        let mut values: RequestHeadersValue = VecDeque::new();
+        values.push_back(value);
+        match self.position(&name) {
+            Some(index) => self.entries[index] = (name, values),
+            None => self.entries.push((name, values)),
+        }
+    }
+
+    /// Appends a value to a header, adding the header if it is missing.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The header name.
+    /// - `String` - The header value.
+    pub(crate) fn append(&mut self, name: String, value: String) {
+        match self.position(&name) {
+            Some(index) => self.entries[index].1.push_back(value),
+            None => self.set(name, value),
+        }
+    }
+
+    /// Removes a header and all of its values.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    pub(crate) fn remove(&mut self, name: &str) {
+        self.entries
+            .retain(|(key, _)| !key.eq_ignore_ascii_case(name));
+    }
+
+    /// Iterates over every header line in order.
+    ///
+    /// A header with several values yields one item per value.
+    ///
+    /// # Returns
+    ///
+    /// - `impl Iterator<Item = (&String, &String)>` - The header names and values.
+    pub(crate) fn lines(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.entries
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+    }
+}
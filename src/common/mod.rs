@@ -1,3 +1,6 @@
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
 pub(crate) mod r#type;
 
+pub(crate) use r#struct::*;
 pub(crate) use r#type::*;
@@ -0,0 +1,11 @@
+use crate::*;
+
+/// Request headers that keep insertion order and allow repeated values.
+///
+/// Header names are matched case-insensitively. Each name appears once, in the
+/// order it was first added, and holds every value in the order it was added.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct OrderedHeaders {
+    /// Header names paired with their values.
+    pub(crate) entries: Vec<(RequestHeadersKey, RequestHeadersValue)>,
+}
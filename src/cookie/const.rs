@@ -0,0 +1,56 @@
+/// Public suffixes under which anyone can register a domain, beyond single-label top-level
+/// domains.
+///
+/// A minimal subset of the Public Suffix List covering common country second-level domains and
+/// shared hosting platforms. A `Domain` attribute naming one of these is rejected so one site
+/// cannot set cookies for every site registered under it.
+pub(crate) const PUBLIC_SUFFIXES: [&str; 48] = [
+    "ac.jp",
+    "ac.uk",
+    "amazonaws.com",
+    "appspot.com",
+    "azurewebsites.net",
+    "blogspot.com",
+    "cloudfront.net",
+    "co.in",
+    "co.jp",
+    "co.kr",
+    "co.nz",
+    "co.uk",
+    "co.za",
+    "com.au",
+    "com.br",
+    "com.cn",
+    "com.hk",
+    "com.mx",
+    "com.sg",
+    "com.tr",
+    "com.tw",
+    "edu.au",
+    "firebaseapp.com",
+    "fly.dev",
+    "github.io",
+    "gitlab.io",
+    "go.jp",
+    "gov.au",
+    "gov.uk",
+    "herokuapp.com",
+    "ltd.uk",
+    "me.uk",
+    "ne.jp",
+    "net.au",
+    "net.cn",
+    "net.uk",
+    "netlify.app",
+    "or.jp",
+    "org.au",
+    "org.cn",
+    "org.nz",
+    "org.uk",
+    "pages.dev",
+    "plc.uk",
+    "s3.amazonaws.com",
+    "vercel.app",
+    "web.app",
+    "workers.dev",
+];
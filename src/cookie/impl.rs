@@ -0,0 +1,532 @@
+use crate::*;
+
+impl Display for StoredCookie {
+    /// Formats the cookie as a `Set-Cookie` header value that `CookieJar::set_cookie` accepts.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)?;
+        if !self.host_only {
+            write!(f, "; Domain={}", self.domain)?;
+        }
+        write!(f, "; Path={}", self.path)?;
+        if let Some(expires) = self.expires {
+            write!(f, "; Expires={}", format_http_date(expires))?;
+        }
+        if self.secure {
+            write!(f, "; Secure")?;
+        }
+        if self.http_only {
+            write!(f, "; HttpOnly")?;
+        }
+        match self.same_site {
+            Some(SameSite::Strict) => write!(f, "; SameSite=Strict"),
+            Some(SameSite::Lax) => write!(f, "; SameSite=Lax"),
+            Some(SameSite::None) => write!(f, "; SameSite=None"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl StoredCookie {
+    /// Gets the cookie name.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The name.
+    pub fn get_name(&self) -> String {
+        self.name.clone()
+    }
+
+    /// Gets the cookie value.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The value.
+    pub fn get_value(&self) -> String {
+        self.value.clone()
+    }
+
+    /// Gets the domain the cookie belongs to.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The lowercase domain.
+    pub fn get_domain(&self) -> String {
+        self.domain.clone()
+    }
+
+    /// Checks whether the cookie is only sent to its exact domain.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True when the cookie was set without a `Domain` attribute.
+    pub fn is_host_only(&self) -> bool {
+        self.host_only
+    }
+
+    /// Gets the path the cookie is scoped to.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The path.
+    pub fn get_path(&self) -> String {
+        self.path.clone()
+    }
+
+    /// Gets the expiry time.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SystemTime>` - The expiry time, or None for a session cookie.
+    pub fn get_expires(&self) -> Option<SystemTime> {
+        self.expires
+    }
+
+    /// Checks whether the cookie is only sent over secure connections.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the cookie has the `Secure` attribute.
+    pub fn is_secure(&self) -> bool {
+        self.secure
+    }
+
+    /// Checks whether the cookie was marked HttpOnly.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the cookie has the `HttpOnly` attribute.
+    pub fn is_http_only(&self) -> bool {
+        self.http_only
+    }
+
+    /// Gets the SameSite attribute.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SameSite>` - The attribute, if present.
+    pub fn get_same_site(&self) -> Option<SameSite> {
+        self.same_site
+    }
+
+    /// Checks whether the cookie has expired.
+    ///
+    /// # Arguments
+    ///
+    /// - `SystemTime` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the expiry time has passed.
+    pub(crate) fn is_expired(&self, now: SystemTime) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    /// Checks whether a protocol is a secure channel for `Secure` cookies.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Protocol` - The request protocol.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - True for `https` and `wss`.
+    fn is_secure_protocol(protocol: &Protocol) -> bool {
+        match protocol {
+            Protocol::Unknown(scheme) => scheme.eq_ignore_ascii_case("wss"),
+            _ => protocol.is_https(),
+        }
+    }
+
+    /// Checks whether a host is an IP address rather than a domain name.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the host is an IPv4 or IPv6 address.
+    fn is_ip_address(host: &str) -> bool {
+        host.parse::<Ipv4Addr>().is_ok()
+            || host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .parse::<Ipv6Addr>()
+                .is_ok()
+    }
+
+    /// Checks whether a cookie domain is a public suffix, such as `com` or `co.uk`.
+    ///
+    /// Single labels are always public suffixes; longer domains are looked up in
+    /// `PUBLIC_SUFFIXES`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The lowercase cookie domain.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether cookies must not be scoped to the domain.
+    pub(crate) fn is_public_suffix(domain: &str) -> bool {
+        !domain.contains('.') || PUBLIC_SUFFIXES.contains(&domain)
+    }
+
+    /// Checks whether a host domain-matches a cookie domain (RFC 6265 section 5.1.3).
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The lowercase request host.
+    /// - `&str` - The lowercase cookie domain.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the host matches the domain.
+    pub(crate) fn domain_matches(host: &str, domain: &str) -> bool {
+        host == domain
+            || (host.len() > domain.len()
+                && host.ends_with(domain)
+                && host.as_bytes()[host.len() - domain.len() - 1] == b'.'
+                && !Self::is_ip_address(host))
+    }
+
+    /// Checks whether a request path path-matches a cookie path (RFC 6265 section 5.1.4).
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request path.
+    /// - `&str` - The cookie path.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the request path is inside the cookie path.
+    pub(crate) fn path_matches(request_path: &str, cookie_path: &str) -> bool {
+        request_path == cookie_path
+            || (request_path.starts_with(cookie_path)
+                && (cookie_path.ends_with('/')
+                    || request_path.as_bytes().get(cookie_path.len()) == Some(&b'/')))
+    }
+
+    /// Computes the default cookie path for a request path (RFC 6265 section 5.1.4).
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request path.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The directory of the request path.
+    pub(crate) fn default_path(request_path: &str) -> String {
+        if !request_path.starts_with('/') {
+            return "/".to_owned();
+        }
+        match request_path.rfind('/') {
+            Some(0) | None => "/".to_owned(),
+            Some(pos) => request_path[..pos].to_owned(),
+        }
+    }
+
+    /// Parses a `Set-Cookie` header received from a URL (RFC 6265 sections 5.2 and 5.3).
+    ///
+    /// Cookies whose `Domain` attribute is a public suffix other than the host, or does not
+    /// domain-match the host, are ignored, as are `Secure` cookies received over `http` or `ws`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The `Set-Cookie` header value.
+    /// - `&HttpUrlComponents` - The URL of the request that received the header.
+    /// - `SystemTime` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<StoredCookie>` - The cookie, or None if it must be ignored.
+    pub(crate) fn parse(
+        set_cookie: &str,
+        url_obj: &HttpUrlComponents,
+        now: SystemTime,
+    ) -> Option<Self> {
+        let mut sections: std::str::Split<'_, char> = set_cookie.split(';');
+        let (name, value) = sections.next()?.split_once('=')?;
+        let name: &str = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        let host: String = url_obj.host.clone().unwrap_or_default().to_lowercase();
+        let request_path: String = url_obj.path.clone().unwrap_or_default();
+        let mut expires: Option<SystemTime> = None;
+        let mut max_age: Option<SystemTime> = None;
+        let mut domain: Option<String> = None;
+        let mut path: Option<String> = None;
+        let mut secure: bool = false;
+        let mut http_only: bool = false;
+        let mut same_site: Option<SameSite> = None;
+        for attribute in sections {
+            let (key, attribute_value) = attribute.split_once('=').unwrap_or((attribute, ""));
+            let key: String = key.trim().to_ascii_lowercase();
+            let attribute_value: &str = attribute_value.trim();
+            match key.as_str() {
+                "expires" => {
+                    if let Some(time) = parse_cookie_date(attribute_value) {
+                        expires = Some(time);
+                    }
+                }
+                "max-age" => {
+                    if let Ok(seconds) = attribute_value.parse::<i64>()
+                        && attribute_value.starts_with(|ch: char| ch.is_ascii_digit() || ch == '-')
+                    {
+                        max_age = Some(if seconds <= 0 {
+                            UNIX_EPOCH
+                        } else {
+                            now.checked_add(Duration::from_secs(seconds as u64))
+                                .unwrap_or(now + Duration::from_secs(u32::MAX as u64))
+                        });
+                    }
+                }
+                "domain" if !attribute_value.is_empty() => {
+                    domain = Some(attribute_value.trim_start_matches('.').to_ascii_lowercase());
+                }
+                "path" => {
+                    path = attribute_value
+                        .starts_with('/')
+                        .then(|| attribute_value.to_owned());
+                }
+                "secure" => secure = true,
+                "httponly" => http_only = true,
+                "samesite" => {
+                    same_site = match attribute_value.to_ascii_lowercase().as_str() {
+                        "strict" => Some(SameSite::Strict),
+                        "lax" => Some(SameSite::Lax),
+                        "none" => Some(SameSite::None),
+                        _ => same_site,
+                    };
+                }
+                _ => {}
+            }
+        }
+        let (domain, host_only): (String, bool) = match domain {
+            Some(domain) if domain != host => {
+                if Self::is_public_suffix(&domain) || !Self::domain_matches(&host, &domain) {
+                    return None;
+                }
+                (domain, false)
+            }
+            Some(domain) if Self::is_public_suffix(&domain) => (host, true),
+            Some(domain) => (domain, false),
+            None => (host, true),
+        };
+        if secure && !Self::is_secure_protocol(&url_obj.protocol) {
+            return None;
+        }
+        Some(Self {
+            name: name.to_owned(),
+            value: value.trim().to_owned(),
+            domain,
+            host_only,
+            path: path.unwrap_or_else(|| Self::default_path(&request_path)),
+            expires: max_age.or(expires),
+            secure,
+            http_only,
+            same_site,
+        })
+    }
+
+    /// Checks whether the cookie should be sent to a URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpUrlComponents` - The request URL.
+    /// - `SystemTime` - The current time.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the cookie matches the host, path and scheme and has not expired.
+    pub(crate) fn matches(&self, url_obj: &HttpUrlComponents, now: SystemTime) -> bool {
+        let host: String = url_obj.host.clone().unwrap_or_default().to_lowercase();
+        let path: String = url_obj
+            .path
+            .clone()
+            .filter(|path| path.starts_with('/'))
+            .unwrap_or_else(|| "/".to_owned());
+        let domain_matches: bool = if self.host_only {
+            host == self.domain
+        } else {
+            Self::domain_matches(&host, &self.domain)
+        };
+        domain_matches
+            && Self::path_matches(&path, &self.path)
+            && (!self.secure || Self::is_secure_protocol(&url_obj.protocol))
+            && !self.is_expired(now)
+    }
+}
+
+/// Two jars are equal when they share the same cookie storage.
+impl PartialEq for CookieJar {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.cookies, &other.cookies)
+    }
+}
+
+impl Eq for CookieJar {}
+
+impl CookieJar {
+    /// Creates an empty cookie jar.
+    ///
+    /// # Returns
+    ///
+    /// - `CookieJar` - An empty jar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a cookie, replacing any cookie with the same name, domain and path.
+    ///
+    /// A cookie that has already expired removes the stored one instead.
+    ///
+    /// # Arguments
+    ///
+    /// - `StoredCookie` - The cookie to store.
+    pub fn add_cookie(&self, cookie: StoredCookie) {
+        let now: SystemTime = SystemTime::now();
+        if let Ok(mut cookies) = self.cookies.write() {
+            cookies.retain(|stored| !stored.is_expired(now));
+            let existing: Option<usize> = cookies.iter().position(|stored| {
+                stored.name == cookie.name
+                    && stored.domain == cookie.domain
+                    && stored.host_only == cookie.host_only
+                    && stored.path == cookie.path
+            });
+            match (existing, cookie.is_expired(now)) {
+                (Some(index), true) => {
+                    cookies.remove(index);
+                }
+                (Some(index), false) => cookies[index] = cookie,
+                (None, true) => {}
+                (None, false) => cookies.push(cookie),
+            }
+        }
+    }
+
+    /// Stores a cookie from a `Set-Cookie` header value received from a URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The `Set-Cookie` header value.
+    /// - `&str` - The URL of the request that received the header.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the cookie was accepted.
+    pub fn set_cookie(&self, set_cookie: &str, url: &str) -> bool {
+        match HttpUrlComponents::parse(url) {
+            Ok(url_obj) => self.store(set_cookie, &url_obj),
+            Err(_) => false,
+        }
+    }
+
+    /// Stores a cookie from a `Set-Cookie` header value received from a parsed URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The `Set-Cookie` header value.
+    /// - `&HttpUrlComponents` - The URL of the request that received the header.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the cookie was accepted.
+    pub(crate) fn store(&self, set_cookie: &str, url_obj: &HttpUrlComponents) -> bool {
+        match StoredCookie::parse(set_cookie, url_obj, SystemTime::now()) {
+            Some(cookie) => {
+                self.add_cookie(cookie);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Stores every `Set-Cookie` header of a response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpResponseBinary` - The response.
+    /// - `&HttpUrlComponents` - The URL of the request that received the response.
+    pub(crate) fn store_response(
+        &self,
+        response: &HttpResponseBinary,
+        url_obj: &HttpUrlComponents,
+    ) {
+        for set_cookie in response.get_header_values(SET_COOKIE) {
+            self.store(&set_cookie, url_obj);
+        }
+    }
+
+    /// Gets the `Cookie` header value to send to a URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The header value, or None when no cookie matches.
+    pub fn get_cookie_header(&self, url: &str) -> Option<String> {
+        HttpUrlComponents::parse(url)
+            .ok()
+            .and_then(|url_obj| self.get_header_value(&url_obj))
+    }
+
+    /// Gets the `Cookie` header value to send to a parsed URL.
+    ///
+    /// Cookies with longer paths are listed first, then older cookies first,
+    /// as recommended by RFC 6265 section 5.4.
+    ///
+    /// # Arguments
+    ///
+    /// - `&HttpUrlComponents` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The header value, or None when no cookie matches.
+    pub(crate) fn get_header_value(&self, url_obj: &HttpUrlComponents) -> Option<String> {
+        let now: SystemTime = SystemTime::now();
+        let cookies: RwLockReadGuard<'_, Vec<StoredCookie>> = self.cookies.read().ok()?;
+        let mut matching: Vec<&StoredCookie> = cookies
+            .iter()
+            .filter(|cookie| cookie.matches(url_obj, now))
+            .collect();
+        if matching.is_empty() {
+            return None;
+        }
+        matching.sort_by_key(|cookie| std::cmp::Reverse(cookie.path.len()));
+        Some(
+            matching
+                .iter()
+                .map(|cookie| format!("{}={}", cookie.name, cookie.value))
+                .collect::<Vec<String>>()
+                .join("; "),
+        )
+    }
+
+    /// Gets every cookie that has not expired, for example to persist the jar.
+    ///
+    /// Each cookie formats as a `Set-Cookie` value that `set_cookie` accepts again.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<StoredCookie>` - The stored cookies in creation order.
+    pub fn get_cookies(&self) -> Vec<StoredCookie> {
+        let now: SystemTime = SystemTime::now();
+        self.cookies.read().map_or(Vec::new(), |cookies| {
+            cookies
+                .iter()
+                .filter(|cookie| !cookie.is_expired(now))
+                .cloned()
+                .collect()
+        })
+    }
+
+    /// Removes every cookie from the jar.
+    pub fn clear(&self) {
+        if let Ok(mut cookies) = self.cookies.write() {
+            cookies.clear();
+        }
+    }
+}
@@ -0,0 +1,9 @@
+pub(crate) mod r#const;
+pub(crate) mod r#enum;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub use r#enum::*;
+pub use r#struct::*;
+
+pub(crate) use r#const::*;
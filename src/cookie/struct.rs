@@ -0,0 +1,38 @@
+use crate::*;
+
+/// A cookie stored in a `CookieJar`, as defined by RFC 6265 section 5.3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoredCookie {
+    /// Cookie name.
+    pub(crate) name: String,
+    /// Cookie value.
+    pub(crate) value: String,
+    /// Lowercase domain the cookie belongs to.
+    pub(crate) domain: String,
+    /// Whether the cookie is only sent to exactly `domain`.
+    pub(crate) host_only: bool,
+    /// Path the cookie is scoped to.
+    pub(crate) path: String,
+    /// Expiry time, or None for a session cookie.
+    pub(crate) expires: Option<SystemTime>,
+    /// Whether the cookie is only sent over secure connections.
+    pub(crate) secure: bool,
+    /// Whether the cookie was marked HttpOnly.
+    pub(crate) http_only: bool,
+    /// The SameSite attribute, if present.
+    pub(crate) same_site: Option<SameSite>,
+}
+
+/// A thread-safe cookie store.
+///
+/// Cloning a jar shares the same cookies, so one jar can be attached to many requests.
+///
+/// `Set-Cookie` headers are stored following RFC 6265: cookies with the `Secure` attribute
+/// received over `http` or `ws` are dropped, and cookies scoped with `Domain` to a public
+/// suffix such as `com` or `co.uk` are rejected. Only a built-in subset of the Public Suffix
+/// List is known, so the check is not exhaustive.
+#[derive(Debug, Clone, Default)]
+pub struct CookieJar {
+    /// Stored cookies in creation order.
+    pub(crate) cookies: ArcRwLock<Vec<StoredCookie>>,
+}
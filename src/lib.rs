@@ -13,23 +13,31 @@
 
 pub(crate) mod body;
 pub(crate) mod cfg;
+pub(crate) mod client;
 pub(crate) mod common;
 pub(crate) mod r#const;
+pub(crate) mod cookie;
+pub(crate) mod multipart;
 pub(crate) mod request;
 pub(crate) mod response;
 pub(crate) mod utils;
 pub(crate) mod websocket;
 
+pub use client::*;
+pub use cookie::*;
+pub use multipart::*;
 pub use request::*;
 pub use response::*;
 pub use websocket::*;
 
+pub use rustls;
+
 pub use http_type::{
     HashMapXxHash3_64, JsonDeserializer, JsonError, JsonMap, JsonNumber, JsonResult,
-    JsonSerializer, JsonStreamDeserializer, JsonValue, hash_map_xx_hash3_64, json_from_reader,
-    json_from_slice, json_from_str, json_from_value, json_to_string, json_to_string_pretty,
-    json_to_value, json_to_vec, json_to_vec_pretty, json_to_writer, json_to_writer_pretty,
-    json_value,
+    JsonSerializer, JsonStreamDeserializer, JsonValue, Method, OptionResponseHeadersValueItem,
+    RequestError, ResponseHeadersValue, hash_map_xx_hash3_64, json_from_reader, json_from_slice,
+    json_from_str, json_from_value, json_to_string, json_to_string_pretty, json_to_value,
+    json_to_vec, json_to_vec_pretty, json_to_writer, json_to_writer_pretty, json_value,
 };
 
 pub(crate) use body::*;
@@ -37,55 +45,95 @@ pub(crate) use common::*;
 pub(crate) use r#const::*;
 pub(crate) use utils::*;
 
+pub(crate) use bytes::Bytes;
 pub(crate) use futures::{Future, Sink, SinkExt, Stream, StreamExt};
+pub(crate) use h2::{
+    Error as H2Error, RecvStream, SendStream,
+    client::{SendRequest, handshake as http2_handshake},
+};
+pub(crate) use http::{
+    Request as Http2Request, Response as Http2Response, StatusCode, Version as Http2Version,
+    request::Builder as Http2RequestBuilder,
+};
 pub(crate) use http_type::{
-    ACCEPT, ACCEPT_ANY, BR_BYTES, CONNECTION, CONTENT_LENGTH, CONTENT_TYPE, Compress, ContentType,
-    DEFAULT_BUFFER_SIZE, DEFAULT_HTTP_PATH, DEFAULT_MAX_REDIRECT_TIMES, DEFAULT_TIMEOUT, EMPTY_STR,
-    HOST, HTTP_BR_BYTES, HttpStatus, HttpUrlComponents, HttpVersion, LOCATION, Method, Protocol,
-    QUERY_SYMBOL, RequestBody, RequestBodyString, RequestError, RequestHeaders, ResponseHeaders,
-    ResponseStatusCode, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, SPACE_U8, TAB_U8, UPGRADE,
-    USER_AGENT,
+    ACCEPT, ACCEPT_ANY, APPLICATION_JSON, APPLICATION_OCTET_STREAM, CHUNKED, CLOSE, CONNECTION,
+    CONTENT_LENGTH, CONTENT_TYPE, COOKIE, Compress, ContentType, DEFAULT_BUFFER_SIZE,
+    DEFAULT_HTTP_PATH, DEFAULT_MAX_REDIRECT_TIMES, DEFAULT_TIMEOUT, EMPTY_STR, FORM_URLENCODED,
+    HOST, HTTP_BR_BYTES, HTTP_VERSION_1_1, HTTP_VERSION_2, HttpStatus, HttpUrlComponents,
+    HttpVersion, KEEP_ALIVE, LOCATION, Protocol, QUERY_SYMBOL, RequestBody, RequestBodyString,
+    RequestHeadersKey, RequestHeadersValue, ResponseHeaders, ResponseStatusCode, SEC_WEBSOCKET_KEY,
+    SEC_WEBSOCKET_PROTOCOL, SEC_WEBSOCKET_VERSION, SET_COOKIE, SPACE_U8, TAB_U8, TEXT_PLAIN,
+    TRANSFER_ENCODING, UPGRADE, USER_AGENT, UTF8,
     tokio::{
-        io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf},
+        fs::File as AsyncFile,
+        io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, ReadBuf, copy as async_copy},
         net::TcpStream as AsyncTcpStream,
-        runtime::Runtime,
-        sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard},
-        time::timeout,
+        runtime::{Builder as RuntimeBuilder, Runtime},
+        sync::{Mutex as AsyncMutex, MutexGuard as AsyncMutexGuard, Notify, futures::Notified},
+        task::{spawn as async_spawn, spawn_blocking},
+        time::{error::Elapsed, timeout},
     },
 };
 pub(crate) use rustls::{
-    ClientConfig, ClientConnection, RootCertStore, StreamOwned, pki_types::ServerName,
+    CertificateError, CipherSuite, ClientConfig, ClientConnection, CommonState, ConfigBuilder,
+    DEFAULT_VERSIONS, DigitallySignedStruct, Error as TlsError, HandshakeKind, KeyLog, KeyLogFile,
+    OtherError, RootCertStore, SignatureScheme, StreamOwned, SupportedCipherSuite,
+    SupportedProtocolVersion, WantsVerifier,
+    client::{
+        Resumption, WantsClientCert, WebPkiServerVerifier,
+        danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier},
+    },
+    crypto::{CryptoProvider, verify_tls12_signature, verify_tls13_signature},
+    pki_types::{
+        CertificateDer, PrivateKeyDer, ServerName, UnixTime,
+        pem::{Error as PemError, PemObject},
+    },
+    version::TLS13,
 };
-pub(crate) use serde::{Serialize, Serializer};
+pub(crate) use rustls_native_certs::{CertificateResult, load_native_certs};
+pub(crate) use serde::{Serialize, Serializer, de::DeserializeOwned, ser::Error as SerializeError};
+pub(crate) use sha2::{Digest, Sha256};
 pub(crate) use std::{
     borrow::Cow,
-    collections::{HashSet, VecDeque},
+    collections::{HashMap, HashSet, VecDeque, hash_map::RandomState},
     fmt::{self, Debug, Display, Formatter},
-    io::{Read, Write},
-    net::{Ipv4Addr, Ipv6Addr, TcpStream},
+    fs::{File, Metadata, OpenOptions, metadata},
+    future::poll_fn,
+    hash::{BuildHasher, Hash},
+    io::{self, ErrorKind, Read, Write},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, TcpStream, ToSocketAddrs},
+    path::{Path, PathBuf},
     pin::Pin,
     str::from_utf8,
     sync::{
-        Arc, RwLock,
-        atomic::{AtomicBool, Ordering},
+        Arc, Condvar, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        mpsc::{Receiver, Sender, channel},
     },
     task::{Context, Poll},
-    time::{Duration, SystemTime, UNIX_EPOCH},
+    thread::spawn,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
     vec::IntoIter,
 };
 pub(crate) use tokio_rustls::{TlsConnector, client::TlsStream};
 pub(crate) use tokio_tungstenite::{
-    MaybeTlsStream, WebSocketStream, client_async_with_config, connect_async_with_config,
-    tungstenite::Message, tungstenite::handshake::client::Request,
+    MaybeTlsStream, WebSocketStream, client_async_with_config,
+    tungstenite::{Error as TungsteniteError, Message, handshake::client::Request},
 };
+pub(crate) use webpki::EndEntityCert;
 pub(crate) use webpki_roots::TLS_SERVER_ROOTS;
 
 #[cfg(test)]
-use std::{
-    sync::{Mutex, MutexGuard},
-    thread::{JoinHandle, spawn},
-    time::Instant,
-};
+use std::{net::TcpListener, sync::atomic::AtomicUsize, thread::JoinHandle};
 
 #[cfg(test)]
 use http_type::tokio;
+#[cfg(test)]
+use rustls::{
+    ServerConfig, ServerConnection,
+    server::{WantsServerCert, WebPkiClientVerifier},
+};
+#[cfg(test)]
+use tokio_rustls::TlsAcceptor;
+#[cfg(test)]
+use tokio_tungstenite::accept_async;
@@ -0,0 +1,11 @@
+/// Media type of multipart form bodies.
+pub(crate) const MULTIPART_FORM_DATA: &str = "multipart/form-data";
+
+/// Prefix of generated multipart boundaries.
+pub(crate) const MULTIPART_BOUNDARY_PREFIX: &str = "----http-request-boundary-";
+
+/// Number of random bytes encoded in a generated multipart boundary.
+pub(crate) const MULTIPART_BOUNDARY_RANDOM_BYTES: usize = 16;
+
+/// Delimiter written before every boundary.
+pub(crate) const MULTIPART_DASHES: &[u8] = b"--";
@@ -0,0 +1,10 @@
+use crate::*;
+
+/// Content of a single multipart form part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum MultipartContent {
+    /// Content held in memory.
+    Bytes(Vec<u8>),
+    /// Content streamed from a file with the given length when the request is sent.
+    File(PathBuf, u64),
+}
@@ -0,0 +1,397 @@
+use crate::*;
+
+/// Default implementation for Multipart.
+///
+/// # Returns
+///
+/// - `Multipart` - An empty form with a newly generated boundary.
+impl Default for Multipart {
+    fn default() -> Self {
+        Self {
+            boundary: Self::generate_boundary(),
+            parts: Vec::new(),
+        }
+    }
+}
+
+impl Multipart {
+    /// Creates an empty multipart form.
+    ///
+    /// # Returns
+    ///
+    /// - `Multipart` - An empty form with a newly generated boundary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generates a random boundary.
+    ///
+    /// The bytes come from the secure random generator of the TLS crypto provider, falling
+    /// back to the randomly keyed hasher of the standard library if it fails.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The boundary.
+    fn generate_boundary() -> String {
+        let mut seed: [u8; MULTIPART_BOUNDARY_RANDOM_BYTES] =
+            [0u8; MULTIPART_BOUNDARY_RANDOM_BYTES];
+        if ClientConfig::builder()
+            .crypto_provider()
+            .secure_random
+            .fill(&mut seed)
+            .is_err()
+        {
+            let state: RandomState = RandomState::new();
+            for (index, chunk) in seed.chunks_mut(8).enumerate() {
+                let random: [u8; 8] = state.hash_one(index).to_le_bytes();
+                chunk.copy_from_slice(&random[..chunk.len()]);
+            }
+        }
+        let mut boundary: String = String::from(MULTIPART_BOUNDARY_PREFIX);
+        for byte in seed {
+            boundary.push_str(&format!("{:02x}", byte));
+        }
+        boundary
+    }
+
+    /// Replaces the boundary until it appears in no part held in memory.
+    ///
+    /// File parts are not read, the random boundary is relied on for them.
+    fn ensure_unique_boundary(&mut self) {
+        while self.parts.iter().any(|part| match &part.content {
+            MultipartContent::Bytes(bytes) => bytes
+                .windows(self.boundary.len())
+                .any(|window| window == self.boundary.as_bytes()),
+            MultipartContent::File(..) => false,
+        }) {
+            self.boundary = Self::generate_boundary();
+        }
+    }
+
+    /// Adds a text field.
+    ///
+    /// # Arguments
+    ///
+    /// - `N` - The field name (must implement ToString).
+    /// - `V` - The field value (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Multipart` - The form for method chaining.
+    pub fn text<N: ToString, V: ToString>(&mut self, name: N, value: V) -> &mut Self {
+        self.parts.push(MultipartPart {
+            name: name.to_string(),
+            filename: None,
+            content_type: None,
+            content: MultipartContent::Bytes(value.to_string().into_bytes()),
+        });
+        self.ensure_unique_boundary();
+        self
+    }
+
+    /// Adds a file part whose content is held in memory.
+    ///
+    /// # Arguments
+    ///
+    /// - `N` - The field name (must implement ToString).
+    /// - `F` - The file name reported to the server (must implement ToString).
+    /// - `C` - The content type of the part (must implement ToString).
+    /// - `D` - The file content (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut Multipart` - The form for method chaining.
+    pub fn bytes<N, F, C, D>(&mut self, name: N, filename: F, content_type: C, data: D) -> &mut Self
+    where
+        N: ToString,
+        F: ToString,
+        C: ToString,
+        D: Into<Vec<u8>>,
+    {
+        self.parts.push(MultipartPart {
+            name: name.to_string(),
+            filename: Some(filename.to_string()),
+            content_type: Some(content_type.to_string()),
+            content: MultipartContent::Bytes(data.into()),
+        });
+        self.ensure_unique_boundary();
+        self
+    }
+
+    /// Adds a file part that is streamed from disk when the request is sent.
+    ///
+    /// The file size is read now so the request `Content-Length` can be computed;
+    /// the request fails if the file size changes before it is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `N` - The field name (must implement ToString).
+    /// - `P` - The path of the file to upload.
+    /// - `F` - The file name reported to the server (must implement ToString).
+    /// - `C` - The content type of the part (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `Result<&mut Multipart, RequestError>` - The form for method chaining, or an error if
+    ///   the file cannot be read.
+    pub fn file<N, P, F, C>(
+        &mut self,
+        name: N,
+        path: P,
+        filename: F,
+        content_type: C,
+    ) -> Result<&mut Self, RequestError>
+    where
+        N: ToString,
+        P: AsRef<Path>,
+        F: ToString,
+        C: ToString,
+    {
+        let path: &Path = path.as_ref();
+        let metadata: Metadata = metadata(path).map_err(|err| {
+            RequestError::Request(format!(
+                "failed to read multipart file {}: {}",
+                path.display(),
+                err
+            ))
+        })?;
+        if !metadata.is_file() {
+            return Err(RequestError::Request(format!(
+                "multipart file {} is not a regular file",
+                path.display()
+            )));
+        }
+        self.parts.push(MultipartPart {
+            name: name.to_string(),
+            filename: Some(filename.to_string()),
+            content_type: Some(content_type.to_string()),
+            content: MultipartContent::File(path.to_path_buf(), metadata.len()),
+        });
+        Ok(self)
+    }
+
+    /// Gets the boundary separating the parts.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The boundary.
+    pub fn get_boundary(&self) -> String {
+        self.boundary.clone()
+    }
+
+    /// Gets the request Content-Type announcing the boundary.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The `multipart/form-data` Content-Type with its boundary parameter.
+    pub(crate) fn get_content_type(&self) -> String {
+        format!("{}; boundary={}", MULTIPART_FORM_DATA, self.boundary)
+    }
+
+    /// Gets the total length of the encoded form.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The number of bytes written by `write_to`.
+    pub(crate) fn get_content_length(&self) -> usize {
+        let parts_length: usize = self
+            .parts
+            .iter()
+            .map(|part| {
+                let content_length: usize = match &part.content {
+                    MultipartContent::Bytes(bytes) => bytes.len(),
+                    MultipartContent::File(_, length) => *length as usize,
+                };
+                self.get_part_header(part).len() + content_length + HTTP_BR_BYTES.len()
+            })
+            .sum();
+        parts_length + self.get_closing_bytes().len()
+    }
+
+    /// Escapes line breaks so a value cannot start another part header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The value to escape.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The value with line breaks percent-encoded.
+    fn escape_line_breaks(value: &str) -> String {
+        value.replace('\r', "%0D").replace('\n', "%0A")
+    }
+
+    /// Escapes a name or file name for a quoted `Content-Disposition` parameter.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The value to escape.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The value with quotes and line breaks percent-encoded.
+    fn escape_quoted(value: &str) -> String {
+        Self::escape_line_breaks(&value.replace('"', "%22"))
+    }
+
+    /// Builds the boundary line and headers that precede a part.
+    ///
+    /// # Arguments
+    ///
+    /// - `&MultipartPart` - The part.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The part header bytes, ending with the blank line before the content.
+    fn get_part_header(&self, part: &MultipartPart) -> Vec<u8> {
+        let mut header: String = format!(
+            "--{}\r\nContent-Disposition: form-data; name=\"{}\"",
+            self.boundary,
+            Self::escape_quoted(&part.name)
+        );
+        if let Some(filename) = &part.filename {
+            header.push_str(&format!("; filename=\"{}\"", Self::escape_quoted(filename)));
+        }
+        header.push_str("\r\n");
+        if let Some(content_type) = &part.content_type {
+            header.push_str(&format!(
+                "Content-Type: {}\r\n",
+                Self::escape_line_breaks(content_type)
+            ));
+        }
+        header.push_str("\r\n");
+        header.into_bytes()
+    }
+
+    /// Builds the closing boundary line.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The closing boundary bytes.
+    fn get_closing_bytes(&self) -> Vec<u8> {
+        let mut closing: Vec<u8> = Vec::with_capacity(self.boundary.len() + 6);
+        closing.extend_from_slice(MULTIPART_DASHES);
+        closing.extend_from_slice(self.boundary.as_bytes());
+        closing.extend_from_slice(MULTIPART_DASHES);
+        closing.extend_from_slice(HTTP_BR_BYTES);
+        closing
+    }
+
+    /// Builds the error returned when a streamed file does not match its recorded size.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Path` - The file path.
+    /// - `u64` - The recorded file size.
+    /// - `u64` - The number of bytes actually read.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestError` - The error.
+    fn file_size_changed(path: &Path, expected: u64, actual: u64) -> RequestError {
+        RequestError::Request(format!(
+            "multipart file {} changed size: expected {} bytes, read {}",
+            path.display(),
+            expected,
+            actual
+        ))
+    }
+
+    /// Checks that every file part can still be read and has the size recorded when it was
+    /// added, so the announced Content-Length matches the bytes that will be sent.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if every file is readable with its recorded size.
+    pub(crate) fn check_files(&self) -> Result<(), RequestError> {
+        for part in &self.parts {
+            if let MultipartContent::File(path, length) = &part.content {
+                let actual: u64 = File::open(path)
+                    .and_then(|file| file.metadata())
+                    .map_err(|err| {
+                        RequestError::Request(format!(
+                            "failed to read multipart file {}: {}",
+                            path.display(),
+                            err
+                        ))
+                    })?
+                    .len();
+                if actual != *length {
+                    return Err(Self::file_size_changed(path, *length, actual));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the encoded form, streaming file parts from disk.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut W` - The writer to send the form to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - Ok if the whole form was written, or the
+    ///   `RequestTimeoutError` if the writer timed out.
+    pub(crate) fn write_to<W: Write + ?Sized>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), HttpRequestError> {
+        for part in &self.parts {
+            writer.write_all(&self.get_part_header(part))?;
+            match &part.content {
+                MultipartContent::Bytes(bytes) => writer.write_all(bytes)?,
+                MultipartContent::File(path, length) => {
+                    let file: File = File::open(path)?;
+                    let copied: u64 = std::io::copy(&mut file.take(*length + 1), writer)?;
+                    if copied != *length {
+                        return Err(Self::file_size_changed(path, *length, copied).into());
+                    }
+                }
+            }
+            writer.write_all(HTTP_BR_BYTES)?;
+        }
+        writer.write_all(&self.get_closing_bytes())?;
+        Ok(())
+    }
+
+    /// Writes the encoded form asynchronously, streaming file parts from disk.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut W` - The async writer to send the form to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok if the whole form was written.
+    pub(crate) async fn write_to_async<W: AsyncWrite + Unpin + ?Sized>(
+        &self,
+        writer: &mut W,
+    ) -> Result<(), RequestError> {
+        let map_err = |err: std::io::Error| RequestError::Request(err.to_string());
+        for part in &self.parts {
+            writer
+                .write_all(&self.get_part_header(part))
+                .await
+                .map_err(map_err)?;
+            match &part.content {
+                MultipartContent::Bytes(bytes) => writer.write_all(bytes).await.map_err(map_err)?,
+                MultipartContent::File(path, length) => {
+                    let file: AsyncFile = AsyncFile::open(path).await.map_err(map_err)?;
+                    let copied: u64 = async_copy(&mut file.take(*length + 1), writer)
+                        .await
+                        .map_err(map_err)?;
+                    if copied != *length {
+                        return Err(Self::file_size_changed(path, *length, copied));
+                    }
+                }
+            }
+            writer.write_all(HTTP_BR_BYTES).await.map_err(map_err)?;
+        }
+        writer
+            .write_all(&self.get_closing_bytes())
+            .await
+            .map_err(map_err)
+    }
+}
@@ -0,0 +1,26 @@
+use crate::*;
+
+/// A `multipart/form-data` request body.
+///
+/// Holds text fields, in-memory file parts and parts streamed from files,
+/// separated by a generated boundary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Multipart {
+    /// Boundary separating the parts.
+    pub(crate) boundary: String,
+    /// Parts in the order they were added.
+    pub(crate) parts: Vec<MultipartPart>,
+}
+
+/// A single part of a multipart form.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct MultipartPart {
+    /// Form field name.
+    pub(crate) name: String,
+    /// File name reported to the server, if any.
+    pub(crate) filename: Option<String>,
+    /// Content type of the part, if any.
+    pub(crate) content_type: Option<String>,
+    /// Part content.
+    pub(crate) content: MultipartContent,
+}
@@ -0,0 +1,16 @@
+use crate::*;
+
+/// Marker starting every PEM section.
+pub(crate) const PEM_BEGIN: &[u8] = b"-----BEGIN";
+
+/// Prefixes accepted in front of a base64 SHA-256 pin, longest first.
+pub(crate) const PIN_SHA256_PREFIXES: [&str; 2] = ["sha256//", "sha256/"];
+
+/// Length of a base64 encoded SHA-256 hash.
+pub(crate) const PIN_SHA256_LENGTH: usize = 44;
+
+/// Number of TLS sessions kept for resumption.
+pub(crate) const TLS_SESSION_CACHE_SIZE: usize = 256;
+
+/// Last id given to changed TLS or resolution settings; default settings have id 0.
+pub(crate) static SETTINGS_ID: AtomicU64 = AtomicU64::new(0);
@@ -0,0 +1,13 @@
+use crate::*;
+
+/// Policy used to verify the certificate chain of a TLS server.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum CertVerification {
+    /// Verifies the chain against the trusted roots.
+    #[default]
+    WebPki,
+    /// Accepts any certificate.
+    AcceptInvalid,
+    /// Delegates to a caller supplied verifier.
+    Custom(Arc<dyn ServerCertVerifier>),
+}
@@ -0,0 +1,10 @@
+use crate::*;
+
+/// Gets a new id for settings that were just changed.
+///
+/// # Returns
+///
+/// - `u64` - An id no other settings have.
+pub(crate) fn next_settings_id() -> u64 {
+    SETTINGS_ID.fetch_add(1, Ordering::Relaxed) + 1
+}
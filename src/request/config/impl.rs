@@ -6,14 +6,26 @@ use crate::*;
 ///
 /// - `Config` - A configuration instance with default values:
 ///   - timeout: DEFAULT_TIMEOUT
+///   - connect_timeout, read_timeout, write_timeout: None, falling back to timeout
+///   - total_timeout: None
+///   - low_speed_limit: None
 ///   - redirect: false
 ///   - max_redirect_times: DEFAULT_MAX_REDIRECT_TIMES
 ///   - buffer: DEFAULT_BUFFER_SIZE
 ///   - decode: true
+///   - cookie_jar: None
+///   - pool: None
+///   - tls: webpki roots only
+///   - dns: the system resolver
 impl Default for Config {
     fn default() -> Self {
         Self {
             timeout: DEFAULT_TIMEOUT,
+            connect_timeout: None,
+            read_timeout: None,
+            write_timeout: None,
+            total_timeout: None,
+            low_speed_limit: None,
             url_obj: HttpUrlComponents::default(),
             redirect: false,
             max_redirect_times: DEFAULT_MAX_REDIRECT_TIMES,
@@ -22,6 +34,627 @@ impl Default for Config {
             buffer: DEFAULT_BUFFER_SIZE,
             decode: true,
             proxy: None,
+            cookie_jar: None,
+            pool: None,
+            tls: TlsConfig::default(),
+            dns: DnsConfig::default(),
         }
     }
 }
+
+impl Config {
+    /// Gets the timeout of a phase of the request.
+    ///
+    /// # Arguments
+    ///
+    /// - `TimeoutPhase` - The phase.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Duration>` - The timeout, or `None` for a total deadline that was not set.
+    pub(crate) fn get_phase_timeout(&self, phase: TimeoutPhase) -> Option<Duration> {
+        let timeout: Option<u64> = match phase {
+            TimeoutPhase::Connect | TimeoutPhase::TlsHandshake | TimeoutPhase::Proxy => {
+                Some(self.connect_timeout.unwrap_or(self.timeout))
+            }
+            TimeoutPhase::Write => Some(self.write_timeout.unwrap_or(self.timeout)),
+            TimeoutPhase::Read => Some(self.read_timeout.unwrap_or(self.timeout)),
+            TimeoutPhase::Total => self.total_timeout,
+        };
+        timeout.map(Duration::from_millis)
+    }
+}
+
+/// Provides the default TLS settings.
+///
+/// # Returns
+///
+/// - `TlsConfig` - Settings trusting only the bundled webpki roots.
+impl Default for TlsConfig {
+    fn default() -> Self {
+        Self {
+            webpki_roots: true,
+            root_certs: Arc::new(Vec::new()),
+            errors: Vec::new(),
+            client_auth: None,
+            verification: CertVerification::default(),
+            spki_pins: Vec::new(),
+            cert_pins: Vec::new(),
+            versions: Vec::new(),
+            cipher_suites: Vec::new(),
+            sni: true,
+            server_name: None,
+            session_resumption: true,
+            key_log: None,
+            cache: TlsConfigCache::default(),
+            proxy_cache: TlsConfigCache::default(),
+            id: 0,
+        }
+    }
+}
+
+/// Compares key logs by identity.
+impl PartialEq for TlsKeyLog {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Default for SharedResolver {
+    fn default() -> Self {
+        Self(Arc::new(SystemResolver))
+    }
+}
+
+/// Compares resolvers by identity.
+impl PartialEq for SharedResolver {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Compares caches by identity.
+impl PartialEq for TlsConfigCache {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// Compares verification policies, treating custom verifiers as equal when they are the same
+/// instance.
+impl PartialEq for CertVerification {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::WebPki, Self::WebPki) | (Self::AcceptInvalid, Self::AcceptInvalid) => true,
+            (Self::Custom(verifier), Self::Custom(other)) => Arc::ptr_eq(verifier, other),
+            _ => false,
+        }
+    }
+}
+
+impl Eq for CertVerification {}
+
+impl Eq for TlsConfig {}
+
+impl Eq for DnsConfig {}
+
+impl TlsConfig {
+    /// Prepares the settings for a change.
+    ///
+    /// Clones of the settings share the built rustls configuration and its session cache, so
+    /// the changed settings get an empty cache and an id of their own.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut TlsConfig` - The settings to change.
+    pub(crate) fn detach_cache(&mut self) -> &mut Self {
+        self.cache = TlsConfigCache::default();
+        self.proxy_cache = TlsConfigCache::default();
+        self.id = next_settings_id();
+        self
+    }
+
+    /// Logs TLS secrets to a file.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Path` - The key log file, appended to and created if needed.
+    pub(crate) fn set_key_log_file(&mut self, path: &Path) {
+        match KeyLogWriter::open(path) {
+            Ok(key_log) => self.key_log = Some(TlsKeyLog(Arc::new(key_log))),
+            Err(err) => self.errors.push(format!(
+                "failed to open key log file {}: {}",
+                path.display(),
+                err
+            )),
+        }
+    }
+
+    /// Sets the name sent in SNI and verified against the target certificate.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The DNS name or IP address.
+    pub(crate) fn set_server_name(&mut self, name: &str) {
+        match ServerName::try_from(name.to_string()) {
+            Ok(server_name) => self.server_name = Some(server_name),
+            Err(err) => self
+                .errors
+                .push(format!("invalid TLS server name {}: {}", name, err)),
+        }
+    }
+
+    /// Returns the server name used for the TLS connection to the target.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host of the URL.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerName<'static>, RequestError>` - The configured server name, otherwise
+    ///   the host.
+    pub(crate) fn get_server_name(&self, host: &str) -> Result<ServerName<'static>, RequestError> {
+        match &self.server_name {
+            Some(server_name) => Ok(server_name.clone()),
+            None => ServerName::try_from(host.to_string())
+                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string())),
+        }
+    }
+
+    /// Adds every certificate of a PEM bundle to the trusted roots.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The PEM data.
+    pub(crate) fn add_root_cert_pem(&mut self, pem: &[u8]) {
+        let mut count: usize = 0;
+        for cert in CertificateDer::pem_slice_iter(pem) {
+            match cert {
+                Ok(cert) => {
+                    Arc::make_mut(&mut self.root_certs).push(cert);
+                    count += 1;
+                }
+                Err(err) => {
+                    self.errors
+                        .push(format!("invalid PEM root certificate: {}", err));
+                    return;
+                }
+            }
+        }
+        if count == 0 {
+            self.errors
+                .push("no certificate found in PEM root certificate data".to_string());
+        }
+    }
+
+    /// Adds a DER-encoded certificate to the trusted roots.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The DER data.
+    pub(crate) fn add_root_cert_der(&mut self, der: &[u8]) {
+        Arc::make_mut(&mut self.root_certs).push(CertificateDer::from(der.to_vec()));
+    }
+
+    /// Adds the certificates of a PEM bundle or DER file to the trusted roots.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Path` - The path of the file.
+    pub(crate) fn add_root_cert_file(&mut self, path: &Path) {
+        match std::fs::read(path) {
+            Ok(data) if Self::is_pem(&data) => self.add_root_cert_pem(&data),
+            Ok(data) => self.add_root_cert_der(&data),
+            Err(err) => self.errors.push(format!(
+                "failed to read root certificate file {}: {}",
+                path.display(),
+                err
+            )),
+        }
+    }
+
+    /// Adds the certificates of the operating system trust store to the trusted roots.
+    ///
+    /// `SSL_CERT_FILE` and `SSL_CERT_DIR` take precedence over the platform store when set.
+    /// Files that cannot be read or parsed are recorded instead of being skipped.
+    pub(crate) fn add_system_root_certs(&mut self) {
+        let result: CertificateResult = load_native_certs();
+        for err in &result.errors {
+            self.errors
+                .push(format!("failed to load system root certificates: {}", err));
+        }
+        if result.certs.is_empty() && result.errors.is_empty() {
+            self.errors
+                .push("no certificate found in the system trust store".to_string());
+        }
+        Arc::make_mut(&mut self.root_certs).extend(result.certs);
+    }
+
+    /// Sets the client certificate chain and private key from PEM data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The PEM certificate chain, leaf first.
+    /// - `&[u8]` - The PEM private key (PKCS#8, PKCS#1 or SEC1).
+    pub(crate) fn set_client_cert_pem(&mut self, cert_chain: &[u8], key: &[u8]) {
+        let cert_chain: Result<Vec<CertificateDer<'static>>, String> =
+            CertificateDer::pem_slice_iter(cert_chain)
+                .collect::<Result<Vec<CertificateDer<'static>>, PemError>>()
+                .map_err(|err| format!("invalid PEM client certificate: {}", err));
+        let key: Result<PrivateKeyDer<'static>, String> = PrivateKeyDer::from_pem_slice(key)
+            .map_err(|err| format!("invalid PEM client private key: {}", err));
+        self.set_client_auth(cert_chain, key);
+    }
+
+    /// Sets the client certificate and private key from DER data.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The DER certificate.
+    /// - `&[u8]` - The DER private key (PKCS#8, PKCS#1 or SEC1).
+    pub(crate) fn set_client_cert_der(&mut self, cert: &[u8], key: &[u8]) {
+        let key: Result<PrivateKeyDer<'static>, String> = PrivateKeyDer::try_from(key.to_vec())
+            .map_err(|err| format!("invalid DER client private key: {}", err));
+        self.set_client_auth(Ok(vec![CertificateDer::from(cert.to_vec())]), key);
+    }
+
+    /// Sets the client certificate chain and private key from PEM or DER files.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Path` - The path of the certificate chain.
+    /// - `&Path` - The path of the private key.
+    pub(crate) fn set_client_cert_file(&mut self, cert_path: &Path, key_path: &Path) {
+        let read = |path: &Path| {
+            std::fs::read(path).map_err(|err| {
+                format!(
+                    "failed to read client certificate file {}: {}",
+                    path.display(),
+                    err
+                )
+            })
+        };
+        match (read(cert_path), read(key_path)) {
+            (Ok(cert_chain), Ok(key)) if Self::is_pem(&cert_chain) || Self::is_pem(&key) => {
+                self.set_client_cert_pem(&cert_chain, &key)
+            }
+            (Ok(cert), Ok(key)) => self.set_client_cert_der(&cert, &key),
+            (Err(err), _) | (_, Err(err)) => self.errors.push(err),
+        }
+    }
+
+    /// Stores a parsed client identity, or the reason it could not be parsed.
+    ///
+    /// # Arguments
+    ///
+    /// - `Result<Vec<CertificateDer<'static>>, String>` - The certificate chain.
+    /// - `Result<PrivateKeyDer<'static>, String>` - The private key.
+    fn set_client_auth(
+        &mut self,
+        cert_chain: Result<Vec<CertificateDer<'static>>, String>,
+        key: Result<PrivateKeyDer<'static>, String>,
+    ) {
+        match (cert_chain, key) {
+            (Ok(cert_chain), _) if cert_chain.is_empty() => self
+                .errors
+                .push("no certificate found in client certificate data".to_string()),
+            (Ok(cert_chain), Ok(key)) => {
+                self.client_auth = Some(Arc::new(ClientAuth { cert_chain, key }));
+            }
+            (Err(err), _) | (_, Err(err)) => self.errors.push(err),
+        }
+    }
+
+    /// Pins the SHA-256 hash of a subject public key info.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The base64 hash, optionally prefixed with `sha256//` or `sha256/`.
+    pub(crate) fn add_spki_pin(&mut self, pin: &str) {
+        if let Some(pin) = self.parse_pin(pin) {
+            self.spki_pins.push(pin);
+        }
+    }
+
+    /// Pins the SHA-256 hash of a DER encoded certificate.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The base64 hash, optionally prefixed with `sha256//` or `sha256/`.
+    pub(crate) fn add_cert_pin(&mut self, pin: &str) {
+        if let Some(pin) = self.parse_pin(pin) {
+            self.cert_pins.push(pin);
+        }
+    }
+
+    /// Normalizes a pin, recording an error if it is not a base64 SHA-256 hash.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The pin.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The base64 hash without prefix, or `None` if it is invalid.
+    fn parse_pin(&mut self, pin: &str) -> Option<String> {
+        let hash: &str = PIN_SHA256_PREFIXES
+            .iter()
+            .find_map(|prefix| pin.trim().strip_prefix(prefix))
+            .unwrap_or(pin.trim());
+        let is_valid: bool = hash.len() == PIN_SHA256_LENGTH
+            && hash.ends_with('=')
+            && hash[..PIN_SHA256_LENGTH - 1]
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || byte == b'+' || byte == b'/');
+        if !is_valid {
+            self.errors.push(format!("invalid SHA-256 pin: {}", pin));
+            return None;
+        }
+        Some(hash.to_string())
+    }
+
+    /// Checks whether certificate or key data is PEM encoded.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The data.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the data holds a PEM section.
+    fn is_pem(data: &[u8]) -> bool {
+        data.windows(PEM_BEGIN.len())
+            .any(|window| window == PEM_BEGIN)
+    }
+
+    /// Returns the rustls configuration shared by every TLS connection, building it on first use.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or an error naming the
+    ///   certificates, keys or pins that could not be loaded.
+    pub(crate) fn get_client_config(&self) -> Result<ClientConfig, RequestError> {
+        Self::get_cached_config(&self.cache, || self.build_client_config())
+    }
+
+    /// Returns the rustls configuration of connections to HTTPS proxies, building it on first use.
+    ///
+    /// Proxies are verified against the trusted roots and receive the client certificate, while
+    /// pins, relaxed or custom verification and SNI settings only apply to the target.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or an error naming the
+    ///   certificates or keys that could not be loaded.
+    pub(crate) fn get_proxy_client_config(&self) -> Result<ClientConfig, RequestError> {
+        Self::get_cached_config(&self.proxy_cache, || {
+            Self {
+                verification: CertVerification::WebPki,
+                spki_pins: Vec::new(),
+                cert_pins: Vec::new(),
+                sni: true,
+                server_name: None,
+                ..self.clone()
+            }
+            .build_client_config()
+        })
+    }
+
+    /// Returns a cached rustls configuration, building it on first use.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TlsConfigCache` - The cache.
+    /// - `F` - Builds the configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or the error of the build.
+    fn get_cached_config<F>(cache: &TlsConfigCache, build: F) -> Result<ClientConfig, RequestError>
+    where
+        F: FnOnce() -> Result<ClientConfig, RequestError>,
+    {
+        let mut cache: MutexGuard<'_, Option<ClientConfig>> = cache
+            .0
+            .lock()
+            .map_err(|err| RequestError::Unknown(err.to_string()))?;
+        if let Some(client_config) = cache.as_ref() {
+            return Ok(client_config.clone());
+        }
+        let client_config: ClientConfig = build()?;
+        *cache = Some(client_config.clone());
+        Ok(client_config)
+    }
+
+    /// Builds a new rustls configuration from the settings.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or an error naming the
+    ///   certificates, keys or pins that could not be loaded.
+    fn build_client_config(&self) -> Result<ClientConfig, RequestError> {
+        let mut provider: CryptoProvider =
+            ClientConfig::builder().crypto_provider().as_ref().clone();
+        if !self.cipher_suites.is_empty() {
+            provider.cipher_suites = self.cipher_suites.clone();
+        }
+        let versions: &[&'static SupportedProtocolVersion] = if self.versions.is_empty() {
+            DEFAULT_VERSIONS
+        } else {
+            &self.versions
+        };
+        let builder: ConfigBuilder<ClientConfig, WantsVerifier> =
+            ClientConfig::builder_with_provider(Arc::new(provider))
+                .with_protocol_versions(versions)
+                .map_err(|err| {
+                    RequestError::TlsConnectorBuild(format!(
+                        "invalid TLS versions or cipher suites: {}",
+                        err
+                    ))
+                })?;
+        let verifier: Arc<dyn ServerCertVerifier> =
+            self.get_cert_verifier(Arc::clone(builder.crypto_provider()))?;
+        let builder: ConfigBuilder<ClientConfig, WantsClientCert> = builder
+            .dangerous()
+            .with_custom_certificate_verifier(verifier);
+        let mut client_config: ClientConfig = match &self.client_auth {
+            Some(client_auth) => builder
+                .with_client_auth_cert(client_auth.cert_chain.clone(), client_auth.key.clone_key())
+                .map_err(|err| {
+                    RequestError::TlsConnectorBuild(format!("invalid client certificate: {}", err))
+                })?,
+            None => builder.with_no_client_auth(),
+        };
+        client_config.enable_sni = self.sni;
+        client_config.key_log = match &self.key_log {
+            Some(key_log) => Arc::clone(&key_log.0),
+            None => Arc::new(KeyLogFile::new()),
+        };
+        client_config.resumption = if self.session_resumption {
+            Resumption::in_memory_sessions(TLS_SESSION_CACHE_SIZE)
+        } else {
+            Resumption::disabled()
+        };
+        Ok(client_config)
+    }
+
+    /// Builds the verifier for the configured policy, checking the pins on top of it.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<CryptoProvider>` - The provider of the configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Arc<dyn ServerCertVerifier>, RequestError>` - The verifier, or an error naming
+    ///   the certificates, keys or pins that could not be loaded.
+    fn get_cert_verifier(
+        &self,
+        provider: Arc<CryptoProvider>,
+    ) -> Result<Arc<dyn ServerCertVerifier>, RequestError> {
+        if !self.errors.is_empty() {
+            return Err(RequestError::TlsConnectorBuild(self.errors.join("; ")));
+        }
+        let verifier: Arc<dyn ServerCertVerifier> = match &self.verification {
+            CertVerification::WebPki => WebPkiServerVerifier::builder_with_provider(
+                Arc::new(self.get_root_cert_store()?),
+                provider,
+            )
+            .build()
+            .map_err(|err| {
+                RequestError::TlsConnectorBuild(format!("invalid trusted roots: {}", err))
+            })?,
+            CertVerification::AcceptInvalid => Arc::new(AcceptInvalidCertsVerifier { provider }),
+            CertVerification::Custom(verifier) => Arc::clone(verifier),
+        };
+        if self.spki_pins.is_empty() && self.cert_pins.is_empty() {
+            return Ok(verifier);
+        }
+        Ok(Arc::new(PinnedCertVerifier {
+            inner: verifier,
+            spki_sha256: self.spki_pins.clone(),
+            cert_sha256: self.cert_pins.clone(),
+        }))
+    }
+
+    /// Builds the store of trusted roots.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<RootCertStore, RequestError>` - The store, or an error if a root certificate is
+    ///   invalid.
+    pub(crate) fn get_root_cert_store(&self) -> Result<RootCertStore, RequestError> {
+        let mut root_cert_store: RootCertStore = RootCertStore::empty();
+        if self.webpki_roots {
+            root_cert_store.extend(TLS_SERVER_ROOTS.iter().cloned());
+        }
+        for cert in self.root_certs.iter() {
+            root_cert_store.add(cert.clone()).map_err(|err| {
+                RequestError::TlsConnectorBuild(format!("invalid root certificate: {}", err))
+            })?;
+        }
+        Ok(root_cert_store)
+    }
+}
+
+impl DnsConfig {
+    /// Prepares the settings for a change, giving the changed settings an id of their own.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut DnsConfig` - The settings to change.
+    pub(crate) fn detach(&mut self) -> &mut Self {
+        self.id = next_settings_id();
+        self
+    }
+
+    /// Caches the lookups of the current resolver.
+    ///
+    /// # Arguments
+    ///
+    /// - `Duration` - How long an answer is reused.
+    pub(crate) fn set_cache_ttl(&mut self, ttl: Duration) {
+        let inner: Arc<dyn Resolver> = self.resolver.0.clone();
+        self.resolver = SharedResolver(Arc::new(CachingResolver::new(inner, ttl)));
+    }
+
+    /// Gets the addresses of a host that need no lookup: IP address literals and overrides.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host.
+    /// - `u16` - The port.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<Vec<SocketAddr>>` - The addresses, or `None` if the resolver has to be asked.
+    pub(crate) fn get_fixed_addrs(&self, host: &str, port: u16) -> Option<Vec<SocketAddr>> {
+        if let Ok(ip) = host.parse::<IpAddr>() {
+            return Some(vec![SocketAddr::new(ip, port)]);
+        }
+        self.overrides.get(&get_resolve_key(host, port)).cloned()
+    }
+
+    /// Resolves a host on a blocking thread so the async runtime keeps running.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host.
+    /// - `u16` - The port.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<Vec<SocketAddr>>` - The addresses.
+    pub(crate) async fn resolve_async(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        if let Some(addrs) = self.get_fixed_addrs(host, port) {
+            return Ok(addrs);
+        }
+        let resolver: Arc<dyn Resolver> = self.resolver.0.clone();
+        let host: String = host.to_string();
+        spawn_blocking(move || resolver.resolve(&host, port))
+            .await
+            .map_err(io::Error::other)?
+    }
+
+    /// Resolves a host and opens an async TCP connection to the first address that accepts it.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host.
+    /// - `u16` - The port.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<AsyncTcpStream>` - The connected stream.
+    pub(crate) async fn connect_async(&self, host: &str, port: u16) -> io::Result<AsyncTcpStream> {
+        let addrs: Vec<SocketAddr> = self.resolve_async(host, port).await?;
+        if addrs.is_empty() {
+            return Err(io::Error::new(
+                ErrorKind::NotFound,
+                format!("no addresses found for {}", host),
+            ));
+        }
+        AsyncTcpStream::connect(addrs.as_slice()).await
+    }
+}
@@ -1,4 +1,10 @@
+pub(crate) mod r#const;
+pub(crate) mod r#enum;
+pub(crate) mod r#fn;
 pub(crate) mod r#impl;
 pub(crate) mod r#struct;
 
+pub(crate) use r#const::*;
+pub(crate) use r#enum::*;
+pub(crate) use r#fn::*;
 pub(crate) use r#struct::*;
@@ -3,10 +3,21 @@ use crate::*;
 /// Configuration for HTTP requests.
 ///
 /// Contains settings like timeout, redirect handling, and proxy configuration.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq)]
 pub(crate) struct Config {
-    /// Request timeout in milliseconds.
+    /// Request timeout in milliseconds, used for every phase without its own timeout.
     pub(crate) timeout: u64,
+    /// Timeout in milliseconds for resolving the host, connecting, the TLS handshake and proxy
+    /// negotiation.
+    pub(crate) connect_timeout: Option<u64>,
+    /// Timeout in milliseconds each read of the response may wait for data.
+    pub(crate) read_timeout: Option<u64>,
+    /// Timeout in milliseconds for writing the request.
+    pub(crate) write_timeout: Option<u64>,
+    /// Deadline in milliseconds for the whole request, redirects included.
+    pub(crate) total_timeout: Option<u64>,
+    /// Minimum speed the response must be received at before the request is aborted.
+    pub(crate) low_speed_limit: Option<LowSpeedLimit>,
     /// Parsed URL components for the request.
     pub(crate) url_obj: HttpUrlComponents,
     /// Whether to follow redirects automatically.
@@ -23,12 +34,92 @@ pub(crate) struct Config {
     pub(crate) decode: bool,
     /// Optional proxy configuration.
     pub(crate) proxy: Option<ProxyConfig>,
+    /// Optional cookie jar shared between requests.
+    pub(crate) cookie_jar: Option<CookieJar>,
+    /// Optional pool of keep-alive connections shared between requests.
+    pub(crate) pool: Option<ConnectionPool>,
+    /// TLS settings for the target and HTTPS proxies.
+    pub(crate) tls: TlsConfig,
+    /// How hosts of the target and proxies are resolved.
+    pub(crate) dns: DnsConfig,
+}
+
+/// Resolution settings shared by every connection of a request.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct DnsConfig {
+    /// Fixed addresses by lowercase host and port, used without a lookup.
+    pub(crate) overrides: HashMap<ResolveKey, Vec<SocketAddr>>,
+    /// Resolver for every other host.
+    pub(crate) resolver: SharedResolver,
+    /// Id of these settings, shared by clones and replaced on every change.
+    pub(crate) id: u64,
+}
+
+/// Resolver set on a builder, the system resolver by default.
+#[derive(Debug, Clone)]
+pub(crate) struct SharedResolver(pub(crate) Arc<dyn Resolver>);
+
+/// TLS settings shared by every TLS connection of a request.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct TlsConfig {
+    /// Whether the bundled webpki roots are trusted.
+    pub(crate) webpki_roots: bool,
+    /// Additional trusted root certificates, shared between clones of the configuration.
+    pub(crate) root_certs: Arc<Vec<CertificateDer<'static>>>,
+    /// Certificates and keys that could not be loaded, reported when a TLS connection is opened.
+    pub(crate) errors: Vec<String>,
+    /// Client certificate presented for mutual TLS.
+    pub(crate) client_auth: Option<Arc<ClientAuth>>,
+    /// Policy verifying the server certificate chain.
+    pub(crate) verification: CertVerification,
+    /// Base64 SHA-256 hashes of pinned subject public key infos.
+    pub(crate) spki_pins: Vec<String>,
+    /// Base64 SHA-256 hashes of pinned DER certificates.
+    pub(crate) cert_pins: Vec<String>,
+    /// Allowed protocol versions, or every version rustls enables by default when empty.
+    pub(crate) versions: Vec<&'static SupportedProtocolVersion>,
+    /// Allowed cipher suites in order of preference, or the provider defaults when empty.
+    pub(crate) cipher_suites: Vec<SupportedCipherSuite>,
+    /// Whether the server name is sent in the SNI extension.
+    pub(crate) sni: bool,
+    /// Name sent in SNI and verified against the target certificate instead of the URL host.
+    pub(crate) server_name: Option<ServerName<'static>>,
+    /// Whether sessions are cached and resumed.
+    pub(crate) session_resumption: bool,
+    /// Destination of TLS secrets, or the `SSLKEYLOGFILE` file when `None`.
+    pub(crate) key_log: Option<TlsKeyLog>,
+    /// Built rustls configuration, shared between clones of the same settings.
+    pub(crate) cache: TlsConfigCache,
+    /// Built rustls configuration of connections to HTTPS proxies, shared like `cache`.
+    pub(crate) proxy_cache: TlsConfigCache,
+    /// Id of these settings, shared by clones and replaced on every change.
+    pub(crate) id: u64,
+}
+
+/// Destination of TLS secrets set on a builder.
+#[derive(Debug, Clone)]
+pub(crate) struct TlsKeyLog(pub(crate) Arc<dyn KeyLog>);
+
+/// Lazily built rustls configuration.
+///
+/// rustls only resumes a session with the verifier instance that established it, so the
+/// configuration and its session cache are built once and reused by every connection.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TlsConfigCache(pub(crate) Arc<Mutex<Option<ClientConfig>>>);
+
+/// Client certificate chain and private key used for mutual TLS.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) struct ClientAuth {
+    /// Certificate chain, leaf first.
+    pub(crate) cert_chain: Vec<CertificateDer<'static>>,
+    /// Private key of the leaf certificate.
+    pub(crate) key: PrivateKeyDer<'static>,
 }
 
 /// Proxy server configuration.
 ///
 /// Contains proxy type, host/port, and optional authentication.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) struct ProxyConfig {
     /// Type of proxy (HTTP/HTTPS/SOCKS5).
     pub(crate) proxy_type: ProxyType,
@@ -43,7 +134,7 @@ pub(crate) struct ProxyConfig {
 }
 
 /// Supported proxy types.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub(crate) enum ProxyType {
     /// HTTP proxy.
     Http,
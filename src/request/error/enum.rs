@@ -0,0 +1,17 @@
+use crate::*;
+
+/// Error returned by a request.
+///
+/// Failures the crate reports with their own details are carried as typed variants, every other
+/// failure as the `RequestError` describing it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpRequestError {
+    /// Request failed for a reason described by a `RequestError`.
+    Request(RequestError),
+    /// Leaf certificate of the server matched no configured pin.
+    CertificatePin(CertificatePinError),
+    /// Phase of the request exceeded its timeout.
+    Timeout(RequestTimeoutError),
+    /// Transfer stayed below the minimum speed for the configured time.
+    LowSpeed(LowSpeedError),
+}
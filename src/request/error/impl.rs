@@ -0,0 +1,60 @@
+use crate::*;
+
+impl std::fmt::Display for HttpRequestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Request(err) => write!(f, "{}", err),
+            Self::CertificatePin(err) => write!(f, "{}", err),
+            Self::Timeout(err) => write!(f, "{}", err),
+            Self::LowSpeed(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for HttpRequestError {}
+
+impl From<RequestError> for HttpRequestError {
+    fn from(err: RequestError) -> Self {
+        Self::Request(err)
+    }
+}
+
+impl From<CertificatePinError> for HttpRequestError {
+    fn from(err: CertificatePinError) -> Self {
+        Self::CertificatePin(err)
+    }
+}
+
+impl From<RequestTimeoutError> for HttpRequestError {
+    fn from(err: RequestTimeoutError) -> Self {
+        Self::Timeout(err)
+    }
+}
+
+impl From<LowSpeedError> for HttpRequestError {
+    fn from(err: LowSpeedError) -> Self {
+        Self::LowSpeed(err)
+    }
+}
+
+/// Converts a stream error, keeping the `RequestTimeoutError` raised by a `TimeoutStream`.
+impl From<io::Error> for HttpRequestError {
+    fn from(err: io::Error) -> Self {
+        match RequestTimeoutError::from_io_error(&err) {
+            Some(timeout_error) => Self::Timeout(timeout_error),
+            None => Self::Request(RequestError::Request(err.to_string())),
+        }
+    }
+}
+
+/// Flattens a request error into a `RequestError` for callers that only handle that type.
+///
+/// Typed failures become a `RequestError::Request` carrying their message.
+impl From<HttpRequestError> for RequestError {
+    fn from(err: HttpRequestError) -> Self {
+        match err {
+            HttpRequestError::Request(err) => err,
+            err => RequestError::Request(err.to_string()),
+        }
+    }
+}
@@ -0,0 +1,4 @@
+pub(crate) mod r#enum;
+pub(crate) mod r#impl;
+
+pub use r#enum::*;
@@ -0,0 +1,46 @@
+use crate::*;
+
+impl KeyLogWriter {
+    /// Opens a key log file for appending, creating it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Path` - The path of the key log file.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<KeyLogWriter>` - The key logger, or the error opening the file.
+    pub(crate) fn open(path: &Path) -> io::Result<Self> {
+        let file: File = OpenOptions::new().append(true).create(true).open(path)?;
+        Ok(Self {
+            file: Mutex::new(file),
+        })
+    }
+
+    /// Encodes bytes as lowercase hexadecimal.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The bytes to encode.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The hexadecimal string.
+    fn hex(data: &[u8]) -> String {
+        data.iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+}
+
+impl KeyLog for KeyLogWriter {
+    fn log(&self, label: &str, client_random: &[u8], secret: &[u8]) {
+        let line: String = format!(
+            "{} {} {}\n",
+            label,
+            Self::hex(client_random),
+            Self::hex(secret)
+        );
+        if let Ok(mut file) = self.file.lock() {
+            let _ = file.write_all(line.as_bytes());
+        }
+    }
+}
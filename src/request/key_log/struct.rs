@@ -0,0 +1,8 @@
+use crate::*;
+
+/// Key logger appending TLS secrets to a file in the NSS key log format read by Wireshark.
+#[derive(Debug)]
+pub(crate) struct KeyLogWriter {
+    /// The key log file, opened for appending.
+    pub(crate) file: Mutex<File>,
+}
@@ -0,0 +1,118 @@
+use crate::*;
+
+impl LowSpeedLimit {
+    /// Creates a minimum transfer speed.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The minimum average speed in bytes per second.
+    /// - `u64` - The time in milliseconds the speed is averaged over.
+    ///
+    /// # Returns
+    ///
+    /// - `LowSpeedLimit` - The limit.
+    pub(crate) fn new(bytes_per_second: u64, time: u64) -> Self {
+        Self {
+            bytes_per_second,
+            time: Duration::from_millis(time.max(1)),
+        }
+    }
+}
+
+impl LowSpeedTracker {
+    /// Starts measuring a transfer.
+    ///
+    /// # Arguments
+    ///
+    /// - `LowSpeedLimit` - The limit the transfer is held to.
+    ///
+    /// # Returns
+    ///
+    /// - `LowSpeedTracker` - The tracker with an empty window.
+    pub(crate) fn new(limit: LowSpeedLimit) -> Self {
+        Self {
+            limit,
+            waited: Duration::ZERO,
+            received: 0,
+        }
+    }
+
+    /// Gets how long the next read may wait before the speed has to be checked.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The time left in the current window.
+    pub(crate) fn get_remaining(&self) -> Duration {
+        self.limit
+            .time
+            .saturating_sub(self.waited)
+            .max(Duration::from_millis(1))
+    }
+
+    /// Records a read, checking the speed once the window is over.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The number of bytes received.
+    /// - `Duration` - How long the read waited.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), LowSpeedError>` - An error if the window ended below the minimum speed.
+    pub(crate) fn record(&mut self, bytes: usize, waited: Duration) -> Result<(), LowSpeedError> {
+        self.received += bytes as u64;
+        self.waited += waited;
+        if self.waited < self.limit.time {
+            return Ok(());
+        }
+        let required: u128 = self.limit.bytes_per_second as u128 * self.waited.as_millis() / 1000;
+        if (self.received as u128) < required {
+            return Err(LowSpeedError {
+                bytes_per_second: self.limit.bytes_per_second,
+                time: self.limit.time,
+            });
+        }
+        self.waited = Duration::ZERO;
+        self.received = 0;
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for LowSpeedError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "transfer below {} bytes/s for {} ms",
+            self.bytes_per_second,
+            self.time.as_millis()
+        )
+    }
+}
+
+impl std::error::Error for LowSpeedError {}
+
+impl From<LowSpeedError> for WebSocketError {
+    fn from(err: LowSpeedError) -> Self {
+        WebSocketError::low_speed(err)
+    }
+}
+
+impl LowSpeedError {
+    /// Gets the minimum speed the transfer fell below.
+    ///
+    /// # Returns
+    ///
+    /// - `u64` - The minimum speed in bytes per second.
+    pub fn get_bytes_per_second(&self) -> u64 {
+        self.bytes_per_second
+    }
+
+    /// Gets the time the transfer stayed below the minimum speed.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The time.
+    pub fn get_time(&self) -> Duration {
+        self.time
+    }
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Minimum transfer speed a response or WebSocket must keep up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct LowSpeedLimit {
+    /// Minimum average speed in bytes per second.
+    pub(crate) bytes_per_second: u64,
+    /// Time the speed is averaged over before the transfer is aborted.
+    pub(crate) time: Duration,
+}
+
+/// Measures a transfer against a `LowSpeedLimit`.
+///
+/// Only time spent waiting for data counts, so a slow consumer does not abort a fast transfer.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct LowSpeedTracker {
+    /// Limit the transfer is held to.
+    pub(crate) limit: LowSpeedLimit,
+    /// Time spent waiting for data in the current window.
+    pub(crate) waited: Duration,
+    /// Bytes received in the current window.
+    pub(crate) received: u64,
+}
+
+/// Error raised when a transfer stays below the minimum speed for the configured time.
+///
+/// Requests return it as `HttpRequestError::LowSpeed`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LowSpeedError {
+    /// Minimum average speed in bytes per second.
+    pub(crate) bytes_per_second: u64,
+    /// Time the speed stayed below the minimum.
+    pub(crate) time: Duration,
+}
@@ -1,13 +1,25 @@
 pub(crate) mod config;
+pub(crate) mod error;
+pub(crate) mod key_log;
+pub(crate) mod low_speed;
 pub(crate) mod proxy;
 pub(crate) mod request;
 pub(crate) mod request_builder;
+pub(crate) mod resolver;
 pub(crate) mod shared;
+pub(crate) mod timeout;
 pub(crate) mod tmp;
+pub(crate) mod verifier;
 
+pub use error::*;
+pub use low_speed::*;
 pub use request::*;
+pub use resolver::*;
+pub use timeout::*;
+pub use verifier::*;
 
 pub(crate) use config::*;
+pub(crate) use key_log::*;
 pub(crate) use proxy::*;
 pub(crate) use shared::*;
 pub(crate) use tmp::*;
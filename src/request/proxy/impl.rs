@@ -95,3 +95,12 @@ impl Write for SyncProxyTunnelStream {
         self.inner.flush()
     }
 }
+
+/// ReadWrite implementation for SyncProxyTunnelStream.
+///
+/// Delegates to the stream to the proxy.
+impl ReadWrite for SyncProxyTunnelStream {
+    fn get_tcp_stream(&self) -> &TcpStream {
+        self.inner.get_tcp_stream()
+    }
+}
@@ -1,4 +1,16 @@
-/// Byte pattern for matching 'content-length' header in HTTP requests.
+/// Maximum length of a chunk-size or trailer line in a chunked response.
 ///
-/// Used for case-sensitive matching of the content-length header.
-pub(crate) const CONTENT_LENGTH_PATTERN: &[u8] = b"content-length:";
+/// Guards against unbounded memory use when a server never terminates the line.
+pub(crate) const MAX_CHUNKED_LINE_LENGTH: usize = 8192;
+
+/// ALPN protocol identifier of HTTP/2 over TLS.
+pub(crate) const ALPN_H2: &[u8] = b"h2";
+
+/// Connection-specific headers that must not be sent over HTTP/2 (RFC 9113 section 8.2.2).
+pub(crate) const HTTP2_CONNECTION_HEADERS: [&str; 5] = [
+    "connection",
+    "keep-alive",
+    "proxy-connection",
+    "transfer-encoding",
+    "upgrade",
+];
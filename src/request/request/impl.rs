@@ -7,12 +7,19 @@ use crate::*;
 /// - `T` - Type implementing AsyncRead + AsyncWrite + Unpin + Send
 impl<T: AsyncRead + AsyncWrite + Unpin + Send> AsyncReadWrite for T {}
 
-/// Blanket implementation for ReadWrite trait.
-///
-/// # Generic Parameters
-///
-/// - `T` - Type implementing Read + Write
-impl<T: Read + Write> ReadWrite for T {}
+/// ReadWrite implementation for a plain TCP socket.
+impl ReadWrite for TcpStream {
+    fn get_tcp_stream(&self) -> &TcpStream {
+        self
+    }
+}
+
+/// ReadWrite implementation for a TLS stream, delegating to the stream it runs over.
+impl<S: ReadWrite> ReadWrite for StreamOwned<ClientConnection, S> {
+    fn get_tcp_stream(&self) -> &TcpStream {
+        self.sock.get_tcp_stream()
+    }
+}
 
 /// Async request trait implementation for HttpRequest.
 ///
@@ -32,6 +39,7 @@ impl AsyncRequestTrait for HttpRequest {
     ///
     /// - `Pin<Box<dyn Future<Output = RequestResult> + Send + '_>>` - Future representing the async request.
     fn send(&mut self) -> Pin<Box<dyn Future<Output = Self::RequestResult> + Send + '_>> {
+        self.start_deadline();
         Box::pin(self.send_async())
     }
 }
@@ -54,6 +62,7 @@ impl RequestTrait for HttpRequest {
     ///
     /// - `RequestResult` - Result of the sync request.
     fn send(&mut self) -> Self::RequestResult {
+        self.start_deadline();
         self.send_sync()
     }
 }
@@ -75,8 +84,9 @@ impl Default for HttpRequest {
         Self {
             methods: Arc::new(Method::new()),
             url: Arc::new(String::new()),
-            header: Arc::new(hash_map_xx_hash3_64()),
+            header: Arc::new(OrderedHeaders::default()),
             body: Arc::new(Body::default()),
+            body_error: None,
             config: Arc::new(RwLock::new(Config::default())),
             tmp: Arc::new(RwLock::new(Tmp::default())),
             response: Arc::new(RwLock::new(HttpResponseBinary::default())),
@@ -88,7 +98,7 @@ impl Default for HttpRequest {
 ///
 /// These methods provide functionality for managing HTTP requests, including:
 /// - Retrieving or setting HTTP attributes (e.g., URL, headers, protocol).
-/// - Constructing and sending HTTP GET or POST requests.
+/// - Constructing and sending HTTP requests for any method.
 /// - Parsing responses and handling redirects.
 impl HttpRequest {
     /// Gets the protocol from config.
@@ -126,8 +136,8 @@ impl HttpRequest {
     ///
     /// # Returns
     ///
-    /// - `RequestHeaders` - The request headers.
-    fn get_header(&self) -> RequestHeaders {
+    /// - `OrderedHeaders` - The request headers.
+    fn get_header(&self) -> OrderedHeaders {
         self.header.as_ref().clone()
     }
 
@@ -174,127 +184,171 @@ impl HttpRequest {
     ///
     /// # Returns
     ///
-    /// A `Vec<u8>` containing the formatted HTTP headers as a byte sequence.
+    /// A `Result<Vec<u8>, RequestError>` containing the formatted HTTP headers as a byte sequence,
+    /// or an error if the body could not be read to compute its length.
     ///
     /// # Notes
     ///
     /// - The `Host` header is derived from the URL's host in the configuration.
     /// - The `Content-Length` header is calculated based on the request method:
-    ///   - For `GET` requests, it is set to `0`.
-    ///   - For other methods, it is determined by the length of the body.
+    ///   - For methods that never carry a body (`GET`, `HEAD`, `TRACE`, `CONNECT`) it is omitted.
+    ///   - For `POST`, `PUT` and `PATCH` it is always set to the length of the body.
+    ///   - For other methods it is only set when the body is not empty.
     /// - If any required header is missing, it is automatically added with its default value.
+    /// - When a body is sent without a `Content-Type`, one is chosen from the body variant.
+    /// - Matching cookies from the configured cookie jar are added to the `Cookie` header.
+    /// - Headers keep the order they were added in, and every value of a repeated header is
+    ///   written on its own line.
     /// - Headers are concatenated into a string with each header ending in a line break specified by `HTTP_BR`.
     ///
     /// # Behavior
     ///
     /// This function ensures that all necessary headers are present and correctly formatted
     /// before constructing the HTTP request.
-    fn header_contains_key_case_insensitive(header: &RequestHeaders, target_key: &str) -> bool {
-        header
-            .keys()
-            .any(|key| key.eq_ignore_ascii_case(target_key))
+    pub(crate) fn get_header_bytes(&self) -> Result<Vec<u8>, RequestError> {
+        let header: OrderedHeaders = self.get_request_headers()?;
+        let estimated_size: usize = header
+            .lines()
+            .map(|(key, value)| key.len() + value.len() + 4)
+            .sum();
+        let mut header_bytes: Vec<u8> = Vec::with_capacity(estimated_size);
+        for (key, value) in header.lines() {
+            header_bytes.extend_from_slice(key.as_bytes());
+            header_bytes.extend_from_slice(b": ");
+            header_bytes.extend_from_slice(value.as_bytes());
+            header_bytes.extend_from_slice(HTTP_BR_BYTES);
+        }
+        Ok(header_bytes)
     }
 
-    /// Converts HTTP headers into formatted HTTP header bytes.
+    /// Gets the headers sent with the request, including the defaults added by the client.
+    ///
+    /// `Host`, `Content-Length`, `Content-Type`, `Accept` and `User-Agent` are added when
+    /// missing, and cookies from the cookie jar are merged into the `Cookie` header.
     ///
     /// # Returns
     ///
-    /// - `Vec<u8>` - The formatted HTTP headers as bytes.
-    pub(crate) fn get_header_bytes(&self) -> Vec<u8> {
-        let mut header: RequestHeaders = self.get_header();
-        let body_length: usize = if self.get_methods().is_get() {
-            0usize
-        } else {
-            self.get_body_bytes().len()
-        };
+    /// - `Result<OrderedHeaders, RequestError>` - The request headers, or an error if the body
+    ///   could not be read.
+    pub(crate) fn get_request_headers(&self) -> Result<OrderedHeaders, RequestError> {
+        let mut header: OrderedHeaders = self.get_header();
+        let body_length: Option<usize> = self.get_request_body_length()?;
         if let Ok(config) = self.config.read() {
-            let host_value: String = config.url_obj.host.clone().unwrap_or_default();
-            let content_length_value: String = body_length.to_string();
-            if !Self::header_contains_key_case_insensitive(&header, HOST) {
+            if !header.contains_key(HOST) {
                 let mut host_deque: VecDeque<String> = VecDeque::new();
-                host_deque.push_front(host_value);
-                header.insert(HOST.to_owned(), host_deque);
+                host_deque.push_back(config.url_obj.host.clone().unwrap_or_default());
+                header.entries.insert(0, (HOST.to_owned(), host_deque));
             }
-            if !Self::header_contains_key_case_insensitive(&header, CONTENT_LENGTH) {
-                let mut content_length_deque: VecDeque<String> = VecDeque::new();
-                content_length_deque.push_front(content_length_value);
-                header.insert(CONTENT_LENGTH.to_owned(), content_length_deque);
+            if let Some(body_length) = body_length
+                && !header.contains_key(CONTENT_LENGTH)
+            {
+                header.set(CONTENT_LENGTH.to_owned(), body_length.to_string());
             }
-            if !Self::header_contains_key_case_insensitive(&header, ACCEPT) {
-                let mut accept_deque: VecDeque<String> = VecDeque::new();
-                accept_deque.push_front(ACCEPT_ANY.to_owned());
-                header.insert(ACCEPT.to_owned(), accept_deque);
+            if body_length.is_some_and(|body_length| body_length > 0)
+                && !header.contains_key(CONTENT_TYPE)
+            {
+                header.set(
+                    CONTENT_TYPE.to_owned(),
+                    self.get_body().get_default_content_type(),
+                );
             }
-            if !Self::header_contains_key_case_insensitive(&header, USER_AGENT) {
-                let mut user_agent_deque: VecDeque<String> = VecDeque::new();
-                user_agent_deque.push_front(APP_NAME.to_owned());
-                header.insert(USER_AGENT.to_owned(), user_agent_deque);
+            if !header.contains_key(ACCEPT) {
+                header.set(ACCEPT.to_owned(), ACCEPT_ANY.to_owned());
             }
-        }
-        let estimated_size: usize = header
-            .iter()
-            .map(|(k, v)| k.len() + v.front().map_or(0, |s| s.len()) + 4)
-            .sum();
-        let mut header_bytes: Vec<u8> = Vec::with_capacity(estimated_size);
-        for (key, value) in &header {
-            header_bytes.extend_from_slice(key.as_bytes());
-            header_bytes.extend_from_slice(b": ");
-            if let Some(header_value) = value.front() {
-                header_bytes.extend_from_slice(header_value.as_bytes());
+            if !header.contains_key(USER_AGENT) {
+                header.set(USER_AGENT.to_owned(), APP_NAME.to_owned());
+            }
+            if let Some(cookie_jar) = &config.cookie_jar
+                && let Some(jar_cookies) = cookie_jar.get_header_value(&config.url_obj)
+            {
+                match header.get_mut(COOKIE).and_then(|value| value.front_mut()) {
+                    Some(explicit_cookies) => {
+                        *explicit_cookies = format!("{}; {}", explicit_cookies, jar_cookies);
+                    }
+                    None => header.set(COOKIE.to_owned(), jar_cookies),
+                }
             }
-            header_bytes.extend_from_slice(HTTP_BR_BYTES);
         }
-        header_bytes
+        Ok(header)
+    }
+
+    /// Serializes the HTTP body into the bytes sent on the wire.
+    ///
+    /// Text and binary bodies are sent unchanged. JSON bodies are serialized according to the
+    /// `Content-Type` header, matched case-insensitively, and default to JSON when it is absent.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The body bytes, or an error if they could not be read.
+    pub(crate) fn get_body_bytes(&self) -> Result<Vec<u8>, RequestError> {
+        let header: OrderedHeaders = self.get_header();
+        let content_type: Option<&String> =
+            header.get(CONTENT_TYPE).and_then(|value| value.front());
+        self.get_body().get_bytes(content_type.map(String::as_str))
     }
 
-    /// Converts the HTTP body into a URL-encoded byte vector (`Vec<u8>`).
+    /// Gets the body bytes that should be written for the current request method.
+    ///
+    /// # Returns
     ///
-    /// This method processes the body of the HTTP request based on the `Content-Type` header.
-    /// If the `Content-Type` is valid and supports conversion, the body is transformed into a
-    /// URL-encoded string and returned as a vector of bytes.
+    /// - `Result<Option<Vec<u8>>, RequestError>` - The body bytes, or `None` when the request is
+    ///   sent without content.
+    pub(crate) fn get_request_body_bytes(&self) -> Result<Option<Vec<u8>>, RequestError> {
+        let methods: Method = self.get_methods();
+        if !SharedRequestBuilder::method_allows_body(&methods) {
+            return Ok(None);
+        }
+        let body_bytes: Vec<u8> = self.get_body_bytes()?;
+        if body_bytes.is_empty() && !SharedRequestBuilder::method_expects_body(&methods) {
+            return Ok(None);
+        }
+        Ok(Some(body_bytes))
+    }
+
+    /// Reports a body that could not be built by the builder.
     ///
     /// # Returns
     ///
-    /// A `Vec<u8>` containing the URL-encoded representation of the HTTP body.
-    /// If the `Content-Type` is not recognized or if the body cannot be converted,
-    /// an empty byte vector is returned.
+    /// - `Result<(), RequestError>` - `RequestError::Request` with the reason if the body could
+    ///   not be built.
+    fn check_body_error(&self) -> Result<(), RequestError> {
+        match &self.body_error {
+            Some(body_error) => Err(RequestError::Request(body_error.clone())),
+            None => Ok(()),
+        }
+    }
+
+    /// Gets the multipart form that is streamed after the request headers.
     ///
-    /// # Notes
+    /// # Returns
     ///
-    /// The `Content-Type` header is matched case-insensitively. If no matching `Content-Type`
-    /// is found or the parsing fails, the method defaults to returning an empty byte vector.
-    /// The body processing relies on the implementation of the `ContentType` parsing logic.
-    /// Converts the HTTP body into URL-encoded bytes.
-    ///
-    /// # Returns
-    ///
-    /// - `Vec<u8>` - The URL-encoded body bytes.
-    pub(crate) fn get_body_bytes(&self) -> Vec<u8> {
-        let header: RequestHeaders = self.get_header();
-        let body: Body = self.get_body();
-        if let Some(content_type_value) = header.get(CONTENT_TYPE) {
-            if let Some(first_value) = content_type_value.front() {
-                let res: String = first_value
-                    .to_lowercase()
-                    .parse::<ContentType>()
-                    .unwrap_or_default()
-                    .get_body_string(&body);
-                return res.into_bytes();
+    /// - `Option<Multipart>` - The form, or `None` when the body is written with the headers.
+    pub(crate) fn get_streamed_body(&self) -> Option<Multipart> {
+        match self.body.as_ref() {
+            Body::Multipart(multipart)
+                if SharedRequestBuilder::method_allows_body(&self.get_methods()) =>
+            {
+                Some(multipart.clone())
             }
+            _ => None,
         }
-        for (key, value) in &header {
-            if key.eq_ignore_ascii_case(CONTENT_TYPE) {
-                if let Some(first_value) = value.front() {
-                    let res: String = first_value
-                        .to_lowercase()
-                        .parse::<ContentType>()
-                        .unwrap_or_default()
-                        .get_body_string(&body);
-                    return res.into_bytes();
-                }
+    }
+
+    /// Gets the length of the body that will be written for the current request method.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<usize>, RequestError>` - The body length, or `None` when the request is
+    ///   sent without content. Multipart files that can no longer be read with their recorded
+    ///   size are reported here, before any part of the request is sent.
+    pub(crate) fn get_request_body_length(&self) -> Result<Option<usize>, RequestError> {
+        match self.get_streamed_body() {
+            Some(multipart) => {
+                multipart.check_files()?;
+                Ok(Some(multipart.get_content_length()))
             }
+            None => Ok(self.get_request_body_bytes()?.map(|body| body.len())),
         }
-        String::new().into_bytes()
     }
 
     /// Retrieves the full path of the HTTP request, including the query string if present.
@@ -339,359 +393,985 @@ impl HttpRequest {
         path
     }
 
-    /// Sends a GET request over the provided stream and returns the HTTP response.
+    /// Sends the request over the provided stream and returns the HTTP response.
     ///
-    /// This method constructs and sends an HTTP GET request to the server. It formats the URL path
-    /// and query parameters based on the current configuration and sends the request to the server
-    /// via the provided `stream`. After sending the request, it waits for the response and reads
-    /// the result.
+    /// This method writes the request line for the configured method, the headers and, when the
+    /// method allows one, the body to the server via the provided `stream`. After sending the
+    /// request, it waits for the response and reads the result.
     ///
     /// # Parameters
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for sending and receiving data.
+    /// - `stream`: A `Box<dyn ReadWrite>`, representing the stream used for sending and
+    ///   receiving data. It is returned to the connection pool when it can be reused.
     ///
     /// # Returns
-    /// Returns a `Result<HttpResponseBinary, RequestError>`, where:
+    /// Returns a `Result<HttpResponseBinary, HttpRequestError>`, where:
     /// - `Ok(HttpResponseBinary)` contains the HTTP response received from the server.
-    /// - `Err(RequestError)` indicates that an error occurred while sending the request or reading the response.
-    fn send_get_request(
+    /// - `Err(HttpRequestError)` indicates that an error occurred while sending the request or reading the response.
+    fn send_request(
         &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-
-        let request: Vec<u8> =
-            SharedRequestBuilder::build_get_request(path, header_bytes, http_version_str);
-
-        stream
-            .write_all(&request)
-            .and_then(|_| stream.flush())
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        mut stream: BoxReadWrite,
+    ) -> Result<BoxResponseTrait, HttpRequestError> {
+        self.write_request(&mut stream)?;
         self.read_response(stream)
     }
 
-    /// Sends a POST request over the provided stream and returns the HTTP response.
+    /// Sends the request over an idle connection taken from the pool.
     ///
-    /// This method constructs and sends an HTTP POST request to the server. It formats the URL path
-    /// and sends the body content along with the headers to the server via the provided `stream`. After
-    /// sending the request, it waits for the response and reads the result.
+    /// # Arguments
     ///
-    /// # Parameters
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for sending and receiving data.
+    /// - `BoxReadWrite` - The pooled stream.
     ///
     /// # Returns
-    /// Returns a `Result<HttpResponseBinary, RequestError>`, where:
-    /// - `Ok(HttpResponseBinary)` contains the HTTP response received from the server.
-    /// - `Err(RequestError)` indicates that an error occurred while sending the request or reading the response.
-    fn send_post_request(
-        &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
+    ///
+    /// - `Option<RequestResult>` - The result, or `None` when the server had already closed the
+    ///   connection before sending anything, in which case the request can be retried.
+    fn send_pooled_request(&mut self, mut stream: BoxReadWrite) -> Option<RequestResult> {
+        if self.write_request(&mut stream).is_err() {
+            return None;
+        }
+        match self.receive_response(&mut stream) {
+            Ok((parser, closed)) if closed && parser.is_empty() => None,
+            Ok((parser, _)) => {
+                self.release_connection(stream, &parser);
+                Some(match parser.finish() {
+                    Ok((response_bytes, redirect_url)) => {
+                        self.handle_response(response_bytes, redirect_url)
+                    }
+                    Err(err) => Err(err.into()),
+                })
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    /// Writes the request line, headers and body to the stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxReadWrite` - The stream to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - Ok when the whole request was written.
+    fn write_request(&self, stream: &mut BoxReadWrite) -> Result<(), HttpRequestError> {
+        let request: Vec<u8> = self.get_request_bytes()?;
+        let timeout_error: RequestTimeoutError =
+            self.set_socket_timeouts(stream.get_tcp_stream(), TimeoutPhase::Write)?;
+        let mut stream: TimeoutStream<'_, dyn ReadWrite> =
+            TimeoutStream::new(stream.as_mut(), timeout_error);
+        stream.write_all(&request)?;
+        if let Some(multipart) = self.get_streamed_body() {
+            multipart.write_to(&mut stream)?;
+        }
+        stream.flush()?;
+        Ok(())
+    }
+
+    /// Builds the complete request bytes for the configured method.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The request line, headers and optional body.
+    ///   Multipart forms are not included because they are streamed separately.
+    fn get_request_bytes(&self) -> Result<Vec<u8>, RequestError> {
+        let methods: Method = self.get_methods();
         let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let body_bytes: Vec<u8> = self.get_body_bytes();
+        let header_bytes: Vec<u8> = self.get_header_bytes()?;
+        let body_bytes: Option<Vec<u8>> = match self.get_streamed_body() {
+            Some(_) => None,
+            None => self.get_request_body_bytes()?,
+        };
         let http_version_str: String =
             self.config.read().map_or("HTTP/1.1".to_string(), |config| {
                 config.http_version.to_string()
             });
-
-        let request: Vec<u8> = SharedRequestBuilder::build_post_request(
+        Ok(SharedRequestBuilder::build_http_request(
+            &methods.to_string(),
             path,
             header_bytes,
             body_bytes,
             http_version_str,
-        );
-
-        stream
-            .write_all(&request)
-            .and_then(|_| stream.flush())
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        self.read_response(stream)
+        ))
     }
 
     /// Reads the HTTP response from the provided stream.
     ///
     /// This method reads the response from the server after sending an HTTP request. It processes the
-    /// headers, checks for redirects, and retrieves the response body based on the content length.
+    /// headers, checks for redirects, and retrieves the response body framed by `Content-Length`,
+    /// chunked transfer coding or connection close, skipping any interim 1xx responses.
+    /// Reusable connections are returned to the pool before a redirect is followed.
     /// If a redirect is detected, it follows the redirection URL. The method ensures that the entire
     /// response is read before returning.
     ///
     /// # Parameters
-    /// - `stream`: A mutable reference to a `Box<dyn ReadWrite>`, representing the stream used
-    ///   for receiving the response.
+    /// - `stream`: A `Box<dyn ReadWrite>`, representing the stream used for receiving the response.
     ///
     /// # Returns
-    /// Returns a `Result<HttpResponseBinary, RequestError>`, where:
+    /// Returns a `Result<HttpResponseBinary, HttpRequestError>`, where:
     /// - `Ok(HttpResponseBinary)` contains the complete HTTP response after processing headers and body.
-    /// - `Err(RequestError)` indicates that an error occurred while reading the response.
+    /// - `Err(HttpRequestError)` indicates that an error occurred while reading the response.
     fn read_response(
         &mut self,
-        stream: &mut Box<dyn ReadWrite>,
-    ) -> Result<BoxResponseTrait, RequestError> {
+        mut stream: BoxReadWrite,
+    ) -> Result<BoxResponseTrait, HttpRequestError> {
+        let (parser, _) = self.receive_response(&mut stream)?;
+        self.release_connection(stream, &parser);
+        let (response_bytes, redirect_url) = parser.finish()?;
+        self.handle_response(response_bytes, redirect_url)
+    }
+
+    /// Reads from the stream until the response is complete or the connection ends.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxReadWrite` - The stream to read from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(ResponseParser, bool), HttpRequestError>` - The parser holding the response, and
+    ///   whether reading stopped because the server closed the connection.
+    fn receive_response(
+        &self,
+        stream: &mut BoxReadWrite,
+    ) -> Result<(ResponseParser, bool), HttpRequestError> {
         let buffer_size: usize = self
             .config
             .read()
             .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
         let mut buffer: Vec<u8> = vec![0; buffer_size];
-        let initial_capacity: usize = buffer_size.max(8192);
-        let mut response_bytes: Vec<u8> = Vec::with_capacity(initial_capacity);
-        let mut headers_done: bool = false;
-        let mut content_length: usize = 0;
-        let mut redirect_url: Option<Vec<u8>> = None;
-        let mut headers_end_pos: usize = 0;
-        let http_version: String = self
-            .config
-            .read()
-            .map_or(HttpVersion::default().to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let http_version_bytes: Vec<u8> = http_version.to_lowercase().into_bytes();
-        let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
-        'read_loop: while let Ok(n) = stream.read(&mut buffer) {
-            if n == 0 {
-                break;
-            }
-            let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
-                &response_bytes,
-                n,
-                response_bytes.capacity(),
-            );
-            if new_capacity > 0 {
-                response_bytes.reserve(new_capacity - response_bytes.capacity());
-            }
-            let old_len: usize = response_bytes.len();
-            response_bytes.extend_from_slice(&buffer[..n]);
-            if !headers_done {
-                let search_start: usize = old_len.saturating_sub(3);
-                if let Some(pos) =
-                    SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
-                {
-                    headers_done = true;
-                    headers_end_pos = pos + 4;
-
-                    SharedResponseHandler::parse_response_headers(
-                        &response_bytes[..headers_end_pos],
-                        &http_version_bytes,
-                        &location_sign_key,
-                        &mut content_length,
-                        &mut redirect_url,
-                    )?;
+        let mut parser: ResponseParser = ResponseParser::new(self.get_methods(), buffer_size);
+        let mut received: bool = false;
+        let mut low_speed: Option<LowSpeedTracker> = self.get_low_speed_tracker();
+        loop {
+            let timeout_error: RequestTimeoutError =
+                self.set_socket_timeouts(stream.get_tcp_stream(), TimeoutPhase::Read)?;
+            let limited: bool = match &low_speed {
+                Some(tracker) => {
+                    Self::limit_read_timeout(stream.get_tcp_stream(), tracker.get_remaining())?
                 }
-            }
-            if headers_done {
-                let total_expected_length: usize = headers_end_pos + content_length;
-                if response_bytes.len() >= total_expected_length {
-                    response_bytes.truncate(total_expected_length);
-                    break 'read_loop;
+                None => false,
+            };
+            let started: Instant = Instant::now();
+            let read_result: io::Result<usize> = stream.read(&mut buffer);
+            let waited: Duration = started.elapsed();
+            match read_result {
+                Ok(0) => return Ok((parser, true)),
+                Ok(n) => {
+                    received = true;
+                    if let Some(tracker) = &mut low_speed {
+                        tracker.record(n, waited)?;
+                    }
+                    if parser.feed(&buffer[..n])? {
+                        return Ok((parser, false));
+                    }
                 }
-            }
-        }
-        self.response = Arc::new(RwLock::new(<HttpResponseBinary as ResponseTrait>::from(
-            &response_bytes,
-        )));
-        if let Ok(config) = self.config.read() {
-            if !config.redirect || redirect_url.is_none() {
-                if config.decode {
-                    if let Ok(mut response) = self.response.write() {
-                        *response = response.decode(config.buffer);
+                Err(err) if RequestTimeoutError::is_timeout_kind(err.kind()) => {
+                    if let Some(tracker) = &mut low_speed {
+                        tracker.record(0, waited)?;
+                    }
+                    if !limited {
+                        return Err(timeout_error.into());
                     }
                 }
-                return Ok(Box::new(
-                    self.response
-                        .read()
-                        .map_or(HttpResponseBinary::default(), |response| response.clone()),
-                ));
+                Err(err) => {
+                    let closed: bool = SharedResponseHandler::is_connection_closed(err.kind());
+                    if !closed && !received {
+                        return Err(RequestError::Request(err.to_string()).into());
+                    }
+                    return Ok((parser, closed));
+                }
             }
         }
-        let url: String = String::from_utf8(redirect_url.unwrap())
-            .map_err(|err| RequestError::InvalidUrl(err.to_string()))?;
-        self.handle_redirect(url)
     }
 
-    /// Handles HTTP redirects by following the redirection URL.
+    /// Returns the stream to the connection pool when the response left it reusable.
     ///
-    /// # Parameters
+    /// # Arguments
+    ///
+    /// - `BoxReadWrite` - The stream the response was read from.
+    /// - `&ResponseParser` - The parser holding the response.
+    fn release_connection(&self, stream: BoxReadWrite, parser: &ResponseParser) {
+        if parser.is_reusable()
+            && let Some((pool, key)) = self.get_pool_key()
+        {
+            pool.put_sync(key, stream, self.get_connection_info());
+        }
+    }
+
+    /// Builds the response and follows a redirect if one was requested.
+    ///
+    /// # Arguments
     ///
-    /// - `url`: The redirection URL to follow.
+    /// - `Vec<u8>` - The raw bytes of the complete response.
+    /// - `Option<Vec<u8>>` - The redirect URL found in the response, if any.
     ///
-    /// Returns `Ok(HttpResponseBinary)` if the redirection is successful, or `Err(RequestError)` otherwise.
-    fn handle_redirect(&mut self, url: String) -> Result<BoxResponseTrait, RequestError> {
-        if let Ok(mut config) = self.config.write() {
-            if !config.redirect {
-                return Err(RequestError::NeedOpenRedirect);
+    /// # Returns
+    ///
+    /// - `Result<BoxResponseTrait, HttpRequestError>` - The final response or an error.
+    fn handle_response(
+        &mut self,
+        response_bytes: Vec<u8>,
+        redirect_url: Option<Vec<u8>>,
+    ) -> Result<BoxResponseTrait, HttpRequestError> {
+        match self.build_response(response_bytes, redirect_url)? {
+            Some(url) => self.handle_redirect(url),
+            None => Ok(Box::new(
+                self.response
+                    .read()
+                    .map_or(HttpResponseBinary::default(), |response| response.clone()),
+            )),
+        }
+    }
+
+    /// Stores the parsed response, its cookies and, when no redirect is followed, decodes it.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The raw bytes of the complete response.
+    /// - `Option<Vec<u8>>` - The redirect URL found in the response, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<String>, RequestError>` - The redirect URL to follow, if any.
+    fn build_response(
+        &mut self,
+        response_bytes: Vec<u8>,
+        redirect_url: Option<Vec<u8>>,
+    ) -> Result<Option<String>, RequestError> {
+        let mut response: HttpResponseBinary =
+            <HttpResponseBinary as ResponseTrait>::from(&response_bytes);
+        response.connection_info = Arc::new(RwLock::new(self.get_connection_info()));
+        self.response = Arc::new(RwLock::new(response));
+        self.store_response_cookies();
+        let (should_redirect, should_decode, buffer_size) = {
+            if let Ok(config) = self.config.read() {
+                (config.redirect, config.decode, config.buffer)
+            } else {
+                (false, false, DEFAULT_BUFFER_SIZE)
             }
-            if let Ok(mut tmp) = self.tmp.clone().write() {
-                if tmp.visit_url.contains(&url) {
-                    return Err(RequestError::RedirectUrlDeadLoop);
-                }
-                tmp.visit_url.insert(url.clone());
-                if config.redirect_times >= config.max_redirect_times {
-                    return Err(RequestError::MaxRedirectTimes);
+        };
+        match redirect_url {
+            Some(redirect_url) if should_redirect => String::from_utf8(redirect_url)
+                .map(Some)
+                .map_err(|err| RequestError::InvalidUrl(err.to_string())),
+            _ => {
+                if should_decode && let Ok(mut response) = self.response.write() {
+                    *response = response.decode(buffer_size);
                 }
-                config.redirect_times += 1;
+                Ok(None)
             }
         }
-        self.url(url.clone());
-        self.send_sync()
     }
 
-    /// Determines the appropriate port for the HTTP request.
+    /// Gets the connection pool of the request and the key of its target.
     ///
-    /// # Parameters
+    /// # Returns
     ///
-    /// - `port`: The default port (if any).
-    /// - `config`: Config
+    /// - `Option<(ConnectionPool, PoolKey)>` - The pool and key, if the request uses a client.
+    fn get_pool_key(&self) -> Option<(ConnectionPool, PoolKey)> {
+        let config: RwLockReadGuard<'_, Config> = self.config.read().ok()?;
+        let pool: ConnectionPool = config.pool.clone()?;
+        let key: PoolKey = PoolKey {
+            protocol: Self::get_protocol(&config).to_string(),
+            host: config.url_obj.host.clone().unwrap_or_default(),
+            port: self.get_port(config.url_obj.port.unwrap_or_default(), &config),
+            proxy: config.proxy.clone(),
+            tls_id: config.tls.id,
+            dns_id: config.dns.id,
+        };
+        Some((pool, key))
+    }
+
+    /// Gets the details of the connection the request is sent on.
     ///
-    /// Returns the resolved port.
-    pub(crate) fn get_port(&self, port: u16, config: &Config) -> u16 {
-        if port != 0 {
-            return port;
+    /// # Returns
+    ///
+    /// - `ConnectionInfo` - The details recorded when the connection was opened.
+    fn get_connection_info(&self) -> ConnectionInfo {
+        self.tmp
+            .read()
+            .map_or(ConnectionInfo::default(), |tmp| tmp.connection_info.clone())
+    }
+
+    /// Records the details of the connection the request is sent on.
+    ///
+    /// # Arguments
+    ///
+    /// - `ConnectionInfo` - The details of the connection.
+    fn set_connection_info(&self, connection_info: ConnectionInfo) {
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.connection_info = connection_info;
         }
-        let protocol: Protocol = Self::get_protocol(config);
-        protocol.get_port()
     }
 
-    /// Establishes a connection stream to the specified host and port.
+    /// Builds the TLS configuration of connections to the target.
     ///
-    /// This method attempts to create a connection stream based on the protocol type
-    /// (HTTP or HTTPS) defined by the current configuration. It supports both plain
-    /// TCP connections and TLS-secured connections. If the protocol is HTTPS, it will
-    /// use the `TlsConnector` to establish a secure TLS connection. For both cases,
-    /// it ensures a read timeout is set on the stream.
+    /// # Returns
     ///
-    /// # Parameters
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or an error if a configured
+    ///   root certificate could not be loaded.
+    fn get_tls_config(&self) -> Result<ClientConfig, RequestError> {
+        self.config
+            .read()
+            .map_err(|err| RequestError::Unknown(err.to_string()))?
+            .tls
+            .get_client_config()
+    }
+
+    /// Builds the TLS configuration of connections to the target, offering the HTTP versions
+    /// of the request through ALPN.
     ///
-    /// - `host`: The hostname or IP address to connect to.
-    /// - `port`: The port number to connect to.
+    /// # Returns
+    ///
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or an error if a configured
+    ///   root certificate could not be loaded.
+    fn get_alpn_tls_config(&self) -> Result<ClientConfig, RequestError> {
+        let mut tls_config: ClientConfig = self.get_tls_config()?;
+        self.set_alpn_protocols(&mut tls_config);
+        Ok(tls_config)
+    }
+
+    /// Builds the TLS configuration of connections to an HTTPS proxy.
     ///
     /// # Returns
     ///
-    /// - `Ok(Box<dyn ReadWrite>)`: A boxed stream that implements the `ReadWrite` trait,
-    ///   representing the established connection.
-    /// - `Err(RequestError)`: An error indicating what went wrong during the connection process.
-    fn get_connection_stream(
-        &self,
-        host: String,
-        port: u16,
-    ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let config: Config = self
-            .config
+    /// - `Result<ClientConfig, RequestError>` - The configuration, or an error if a configured
+    ///   root certificate could not be loaded.
+    fn get_proxy_tls_config(&self) -> Result<ClientConfig, RequestError> {
+        self.config
             .read()
-            .map_or(Config::default(), |config| config.clone());
-        if let Some(proxy_config) = &config.proxy {
-            return self.get_proxy_connection_stream(host, port, proxy_config);
-        }
-        let host_port: (String, u16) = (host.clone(), port);
-        let timeout: Duration = Duration::from_millis(config.timeout);
-        let tcp_stream: TcpStream = TcpStream::connect(host_port.clone())
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        tcp_stream
-            .set_read_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
-        tcp_stream
-            .set_write_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
-        let stream: Result<Box<dyn ReadWrite>, RequestError> =
-            if Self::get_protocol(&config).is_https() {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => {
-                        let roots: RootCertStore = tmp.root_cert.clone();
-                        let tls_config: ClientConfig = ClientConfig::builder()
-                            .with_root_certificates(roots)
-                            .with_no_client_auth();
-                        let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                        let dns_name: ServerName<'_> = ServerName::try_from(host.clone())
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                        let session: ClientConnection =
-                            ClientConnection::new(Arc::clone(&client_config), dns_name)
-                                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                        let tls_stream: StreamOwned<ClientConnection, TcpStream> =
-                            StreamOwned::new(session, tcp_stream);
-                        return Ok(Box::new(tls_stream));
-                    }
-                    Err(err) => Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    ))),
-                }
-            } else {
-                Ok(Box::new(tcp_stream))
-            };
-        stream
+            .map_err(|err| RequestError::Unknown(err.to_string()))?
+            .tls
+            .get_proxy_client_config()
     }
 
-    /// Establishes a proxy connection stream to the specified host and port.
-    fn get_proxy_connection_stream(
-        &self,
-        target_host: String,
-        target_port: u16,
+    /// Returns the server name of the TLS connection to the target.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host of the URL.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerName<'static>, RequestError>` - The configured override, otherwise the host.
+    fn get_tls_server_name(&self, host: &str) -> Result<ServerName<'static>, RequestError> {
+        self.config
+            .read()
+            .map_err(|err| RequestError::Unknown(err.to_string()))?
+            .tls
+            .get_server_name(host)
+    }
+
+    /// Returns the server name of the TLS connection to an HTTPS proxy.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ProxyConfig` - The proxy configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<ServerName<'static>, RequestError>` - The host of the proxy.
+    fn get_proxy_server_name(
         proxy_config: &ProxyConfig,
-    ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let timeout: Duration = Duration::from_millis(
-            self.config
-                .read()
-                .map_or(DEFAULT_TIMEOUT, |config| config.timeout),
-        );
-        match proxy_config.proxy_type {
-            ProxyType::Http | ProxyType::Https => {
-                self.get_http_proxy_connection(target_host, target_port, proxy_config, timeout)
-            }
-            ProxyType::Socks5 => {
-                self.get_socks5_proxy_connection(target_host, target_port, proxy_config, timeout)
-            }
-        }
+    ) -> Result<ServerName<'static>, RequestError> {
+        ServerName::try_from(proxy_config.host.clone())
+            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))
     }
 
-    /// Establishes an HTTP/HTTPS proxy connection.
+    /// Opens a synchronous TLS session over a stream and completes its handshake within the
+    /// connect timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `S` - The stream to the server.
+    /// - `ServerName<'static>` - The name sent in SNI and verified against the certificate.
+    /// - `ClientConfig` - The TLS configuration of the target or of the proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<StreamOwned<ClientConnection, S>, HttpRequestError>` - The TLS stream.
+    fn connect_tls<S: ReadWrite>(
+        &self,
+        stream: S,
+        server_name: ServerName<'static>,
+        tls_config: ClientConfig,
+    ) -> Result<StreamOwned<ClientConnection, S>, HttpRequestError> {
+        let timeout_error: RequestTimeoutError =
+            self.set_socket_timeouts(stream.get_tcp_stream(), TimeoutPhase::TlsHandshake)?;
+        let session: ClientConnection = ClientConnection::new(Arc::new(tls_config), server_name)
+            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+        let mut tls_stream: StreamOwned<ClientConnection, S> = StreamOwned::new(session, stream);
+        Self::complete_tls_handshake(&mut tls_stream, timeout_error)?;
+        Ok(tls_stream)
+    }
+
+    /// Opens an asynchronous TLS session over a stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `S` - The stream to the server.
+    /// - `ServerName<'static>` - The name sent in SNI and verified against the certificate.
+    /// - `ClientConfig` - The TLS configuration of the target or of the proxy.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TlsStream<S>, HttpRequestError>` - The TLS stream.
+    async fn connect_tls_async<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        stream: S,
+        server_name: ServerName<'static>,
+        tls_config: ClientConfig,
+    ) -> Result<TlsStream<S>, HttpRequestError> {
+        self.with_timeout(TimeoutPhase::TlsHandshake, async {
+            TlsConnector::from(Arc::new(tls_config))
+                .connect(server_name, stream)
+                .await
+                .map_err(Self::get_tls_handshake_error)
+        })
+        .await
+    }
+
+    /// Starts the deadline of the whole request when a total timeout is configured.
+    ///
+    /// Redirects are followed within the same deadline.
+    fn start_deadline(&self) {
+        let total: Option<Duration> = self
+            .config
+            .read()
+            .ok()
+            .and_then(|config| config.get_phase_timeout(TimeoutPhase::Total));
+        if let Ok(mut tmp) = self.tmp.write() {
+            tmp.deadline = total.map(|total| Instant::now() + total);
+        }
+    }
+
+    /// Gets how long a phase of the request may take.
+    ///
+    /// # Arguments
+    ///
+    /// - `TimeoutPhase` - The phase about to run.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(RequestTimeoutError, Duration), HttpRequestError>` - The error reported if the
+    ///   phase runs out of time, which names the total deadline when that comes first, and how
+    ///   long the phase may take. Fails if the deadline has already passed.
+    fn get_phase_timeout(
+        &self,
+        phase: TimeoutPhase,
+    ) -> Result<(RequestTimeoutError, Duration), HttpRequestError> {
+        let (duration, total) =
+            self.config
+                .read()
+                .map_or((Duration::from_millis(DEFAULT_TIMEOUT), None), |config| {
+                    (
+                        config
+                            .get_phase_timeout(phase)
+                            .unwrap_or(Duration::from_millis(config.timeout)),
+                        config.get_phase_timeout(TimeoutPhase::Total),
+                    )
+                });
+        let deadline: Option<Instant> = self.tmp.read().ok().and_then(|tmp| tmp.deadline);
+        if let (Some(deadline), Some(total)) = (deadline, total) {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            let total_error: RequestTimeoutError =
+                RequestTimeoutError::new(TimeoutPhase::Total, total);
+            if remaining.is_zero() {
+                return Err(total_error.into());
+            }
+            if remaining < duration {
+                return Ok((total_error, remaining));
+            }
+        }
+        Ok((RequestTimeoutError::new(phase, duration), duration))
+    }
+
+    /// Applies the timeout of a phase to the socket of a synchronous stream.
+    ///
+    /// Reads and writes wait at most that long, except that only the read timeout changes for
+    /// `TimeoutPhase::Read` and only the write timeout for `TimeoutPhase::Write`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TcpStream` - The socket.
+    /// - `TimeoutPhase` - The phase about to run.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<RequestTimeoutError, HttpRequestError>` - The error reported if the socket times out.
+    fn set_socket_timeouts(
+        &self,
+        socket: &TcpStream,
+        phase: TimeoutPhase,
+    ) -> Result<RequestTimeoutError, HttpRequestError> {
+        let (timeout_error, duration) = self.get_phase_timeout(phase)?;
+        if phase != TimeoutPhase::Write {
+            socket
+                .set_read_timeout(Some(duration))
+                .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
+        }
+        if phase != TimeoutPhase::Read {
+            socket
+                .set_write_timeout(Some(duration))
+                .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
+        }
+        Ok(timeout_error)
+    }
+
+    /// Starts measuring the speed of a response when a low-speed limit is configured.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<LowSpeedTracker>` - The tracker, or `None` without a limit.
+    fn get_low_speed_tracker(&self) -> Option<LowSpeedTracker> {
+        self.config
+            .read()
+            .ok()
+            .and_then(|config| config.low_speed_limit)
+            .map(LowSpeedTracker::new)
+    }
+
+    /// Shortens the read timeout of a socket so a read returns when the low-speed window ends.
+    ///
+    /// # Arguments
+    ///
+    /// - `&TcpStream` - The socket.
+    /// - `Duration` - The time left in the low-speed window.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<bool, RequestError>` - Whether the window is shorter than the read timeout.
+    fn limit_read_timeout(socket: &TcpStream, window: Duration) -> Result<bool, RequestError> {
+        let read_timeout: Option<Duration> = socket
+            .read_timeout()
+            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
+        if read_timeout.is_some_and(|read_timeout| read_timeout <= window) {
+            return Ok(false);
+        }
+        socket
+            .set_read_timeout(Some(window))
+            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
+        Ok(true)
+    }
+
+    /// Resolves the host and opens a TCP connection within the connect timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host to connect to.
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<TcpStream, HttpRequestError>` - The connected socket.
+    fn connect_tcp(&self, host: &str, port: u16) -> Result<TcpStream, HttpRequestError> {
+        let (timeout_error, duration) = self.get_phase_timeout(TimeoutPhase::Connect)?;
+        let deadline: Instant = Instant::now() + duration;
+        let addrs: Vec<SocketAddr> = self
+            .resolve_host(host, port, duration)
+            .ok_or(timeout_error)?
+            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
+        let mut last_error: Option<io::Error> = None;
+        for addr in addrs {
+            let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Err(timeout_error.into());
+            }
+            match TcpStream::connect_timeout(&addr, remaining) {
+                Ok(tcp_stream) => return Ok(tcp_stream),
+                Err(err) => last_error = Some(err),
+            }
+        }
+        match last_error {
+            Some(err) if RequestTimeoutError::is_timeout_kind(err.kind()) => {
+                Err(timeout_error.into())
+            }
+            Some(err) => Err(RequestError::TcpStreamConnect(err.to_string()).into()),
+            None => Err(
+                RequestError::TcpStreamConnect(format!("no addresses found for {}", host)).into(),
+            ),
+        }
+    }
+
+    /// Gets the resolution settings of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `DnsConfig` - The overrides and resolver.
+    fn get_dns_config(&self) -> DnsConfig {
+        self.config
+            .read()
+            .map_or(DnsConfig::default(), |config| config.dns.clone())
+    }
+
+    /// Resolves a host with the configured resolver on a separate thread, so the lookup can be
+    /// abandoned after a timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host name or IP address.
+    /// - `u16` - The port.
+    /// - `Duration` - How long to wait for the lookup.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<ResolveResult>` - The addresses, or `None` if the lookup timed out.
+    fn resolve_host(&self, host: &str, port: u16, timeout: Duration) -> Option<ResolveResult> {
+        let dns: DnsConfig = self.get_dns_config();
+        if let Some(addrs) = dns.get_fixed_addrs(host, port) {
+            return Some(Ok(addrs));
+        }
+        let resolver: Arc<dyn Resolver> = dns.resolver.0;
+        let host: String = host.to_string();
+        let (sender, receiver): (Sender<ResolveResult>, Receiver<ResolveResult>) = channel();
+        spawn(move || {
+            let _ = sender.send(resolver.resolve(&host, port));
+        });
+        receiver.recv_timeout(timeout).ok()
+    }
+
+    /// Runs a phase of an async request, failing if it exceeds the configured timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `TimeoutPhase` - The phase reported when the timeout is exceeded.
+    /// - `F` - The future running the phase.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, HttpRequestError>` - The output of the phase, or a `RequestTimeoutError`.
+    async fn with_timeout<T, E: Into<HttpRequestError>, F: Future<Output = Result<T, E>>>(
+        &self,
+        phase: TimeoutPhase,
+        future: F,
+    ) -> Result<T, HttpRequestError> {
+        let (timeout_error, duration) = self.get_phase_timeout(phase)?;
+        timeout(duration, future)
+            .await
+            .map_err(|_| timeout_error)?
+            .map_err(Into::into)
+    }
+
+    /// Runs a read of an async response within the read timeout, giving up early when the
+    /// low-speed window ends first.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<&LowSpeedTracker>` - The tracker of the response, if a limit is configured.
+    /// - `F` - The future performing the read.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<T>, HttpRequestError>` - The output of the read, or `None` if the low-speed
+    ///   window ended first.
+    async fn read_within_window<T, F: Future<Output = T>>(
+        &self,
+        low_speed: Option<&LowSpeedTracker>,
+        future: F,
+    ) -> Result<Option<T>, HttpRequestError> {
+        let read = self.with_timeout(TimeoutPhase::Read, async {
+            Ok::<T, HttpRequestError>(future.await)
+        });
+        match low_speed.map(LowSpeedTracker::get_remaining) {
+            Some(window) => timeout(window, read).await.ok().transpose(),
+            None => read.await.map(Some),
+        }
+    }
+
+    /// Resolves the host and opens an async TCP connection within the configured timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `(String, u16)` - The host and port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<AsyncTcpStream, HttpRequestError>` - The connected stream.
+    async fn connect_tcp_async(
+        &self,
+        host_port: (String, u16),
+    ) -> Result<AsyncTcpStream, HttpRequestError> {
+        let dns: DnsConfig = self.get_dns_config();
+        let (host, port) = host_port;
+        self.with_timeout(TimeoutPhase::Connect, async {
+            dns.connect_async(&host, port)
+                .await
+                .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))
+        })
+        .await
+    }
+
+    /// Completes the TLS handshake of a synchronous stream so its parameters can be recorded.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut StreamOwned<ClientConnection, S>` - The TLS stream.
+    /// - `RequestTimeoutError` - The error reported if the socket times out.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - An error if the handshake failed.
+    fn complete_tls_handshake<S: Read + Write>(
+        tls_stream: &mut StreamOwned<ClientConnection, S>,
+        timeout_error: RequestTimeoutError,
+    ) -> Result<(), HttpRequestError> {
+        if tls_stream.conn.is_handshaking() {
+            tls_stream
+                .conn
+                .complete_io(&mut TimeoutStream::new(&mut tls_stream.sock, timeout_error))
+                .map_err(Self::get_tls_handshake_error)?;
+        }
+        Ok(())
+    }
+
+    /// Converts the error of a failed TLS handshake.
+    ///
+    /// # Arguments
+    ///
+    /// - `io::Error` - The error returned by the handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `HttpRequestError` - The `CertificatePinError` if no certificate matched a pin, the
+    ///   `RequestTimeoutError` if the handshake timed out, otherwise
+    ///   `RequestError::TlsConnectorBuild` with the error message.
+    fn get_tls_handshake_error(err: io::Error) -> HttpRequestError {
+        if let Some(timeout_error) = RequestTimeoutError::from_io_error(&err) {
+            return timeout_error.into();
+        }
+        match CertificatePinError::from_io_error(&err) {
+            Some(pin_error) => pin_error.clone().into(),
+            None => RequestError::TlsConnectorBuild(err.to_string()).into(),
+        }
+    }
+
+    /// Stores the `Set-Cookie` headers of the last response in the configured cookie jar.
+    fn store_response_cookies(&self) {
+        if let Ok(config) = self.config.read()
+            && let Some(cookie_jar) = &config.cookie_jar
+            && let Ok(response) = self.response.read()
+        {
+            cookie_jar.store_response(&response, &config.url_obj);
+        }
+    }
+
+    /// Handles HTTP redirects by following the redirection URL.
+    ///
+    /// # Parameters
+    ///
+    /// - `url`: The redirection URL to follow, resolved against the current URL when relative so
+    ///   that the cookie jar matches cookies against the host and path actually requested next.
+    ///
+    /// Returns `Ok(HttpResponseBinary)` if the redirection is successful, or `Err(HttpRequestError)` otherwise.
+    fn handle_redirect(&mut self, url: String) -> Result<BoxResponseTrait, HttpRequestError> {
+        let url: String = resolve_url(&self.get_url(), &url);
+        if let Ok(mut config) = self.config.write() {
+            if !config.redirect {
+                return Err(RequestError::NeedOpenRedirect.into());
+            }
+            if let Ok(mut tmp) = self.tmp.clone().write() {
+                if tmp.visit_url.contains(&url) {
+                    return Err(RequestError::RedirectUrlDeadLoop.into());
+                }
+                tmp.visit_url.insert(url.clone());
+                if config.redirect_times >= config.max_redirect_times {
+                    return Err(RequestError::MaxRedirectTimes.into());
+                }
+                config.redirect_times += 1;
+            }
+        }
+        self.url(url.clone());
+        self.send_sync()
+    }
+
+    /// Determines the appropriate port for the HTTP request.
+    ///
+    /// # Parameters
+    ///
+    /// - `port`: The default port (if any).
+    /// - `config`: Config
+    ///
+    /// Returns the resolved port.
+    pub(crate) fn get_port(&self, port: u16, config: &Config) -> u16 {
+        if port != 0 {
+            return port;
+        }
+        let protocol: Protocol = Self::get_protocol(config);
+        protocol.get_port()
+    }
+
+    /// Establishes a connection stream to the specified host and port.
+    ///
+    /// This method attempts to create a connection stream based on the protocol type
+    /// (HTTP or HTTPS) defined by the current configuration. It supports both plain
+    /// TCP connections and TLS-secured connections. If the protocol is HTTPS, it will
+    /// use the `TlsConnector` to establish a secure TLS connection. For both cases,
+    /// it ensures a read timeout is set on the stream.
+    /// The details of the connection are recorded so they can be exposed on the response.
+    ///
+    /// # Parameters
+    ///
+    /// - `host`: The hostname or IP address to connect to.
+    /// - `port`: The port number to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(Box<dyn ReadWrite>)`: A boxed stream that implements the `ReadWrite` trait,
+    ///   representing the established connection.
+    /// - `Err(HttpRequestError)`: An error indicating what went wrong during the connection process.
+    fn get_connection_stream(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<Box<dyn ReadWrite>, HttpRequestError> {
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if let Some(proxy_config) = &config.proxy {
+            return self.get_proxy_connection_stream(host, port, proxy_config);
+        }
+        let tcp_stream: TcpStream = self.connect_tcp(&host, port)?;
+        let mut connection_info: ConnectionInfo =
+            ConnectionInfo::from_addrs(tcp_stream.peer_addr(), tcp_stream.local_addr());
+        if Self::get_protocol(&config).is_https() {
+            let tls_stream: StreamOwned<ClientConnection, TcpStream> = self.connect_tls(
+                tcp_stream,
+                self.get_tls_server_name(&host)?,
+                self.get_tls_config()?,
+            )?;
+            connection_info.set_tls(&tls_stream.conn);
+            self.set_connection_info(connection_info);
+            return Ok(Box::new(tls_stream));
+        }
+        self.set_connection_info(connection_info);
+        Ok(Box::new(tcp_stream))
+    }
+
+    /// Establishes a proxy connection stream to the specified host and port.
+    fn get_proxy_connection_stream(
+        &self,
+        target_host: String,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+    ) -> Result<Box<dyn ReadWrite>, HttpRequestError> {
+        match proxy_config.proxy_type {
+            ProxyType::Http | ProxyType::Https => {
+                self.get_http_proxy_connection(target_host, target_port, proxy_config)
+            }
+            ProxyType::Socks5 => {
+                self.get_socks5_proxy_connection(target_host, target_port, proxy_config)
+            }
+        }
+    }
+
+    /// Establishes an HTTP/HTTPS proxy connection.
     fn get_http_proxy_connection(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
-        timeout: Duration,
-    ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: TcpStream = TcpStream::connect(proxy_host_port)
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        tcp_stream
-            .set_read_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
-        tcp_stream
-            .set_write_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
+    ) -> Result<Box<dyn ReadWrite>, HttpRequestError> {
+        let tcp_stream: TcpStream = self.connect_tcp(&proxy_config.host, proxy_config.port)?;
+        let mut connection_info: ConnectionInfo =
+            ConnectionInfo::from_addrs(tcp_stream.peer_addr(), tcp_stream.local_addr());
+        connection_info.set_proxy(proxy_config);
         let mut proxy_stream: Box<dyn ReadWrite> = if proxy_config.proxy_type == ProxyType::Https {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                        .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name)
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let tls_stream: StreamOwned<ClientConnection, TcpStream> =
-                        StreamOwned::new(session, tcp_stream);
-                    Box::new(tls_stream)
-                }
-                Err(err) => {
-                    return Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    )));
-                }
-            }
+            let tls_stream: StreamOwned<ClientConnection, TcpStream> = self.connect_tls(
+                tcp_stream,
+                Self::get_proxy_server_name(proxy_config)?,
+                self.get_proxy_tls_config()?,
+            )?;
+            Box::new(tls_stream)
         } else {
             Box::new(tcp_stream)
         };
+        let timeout_error: RequestTimeoutError =
+            self.set_socket_timeouts(proxy_stream.get_tcp_stream(), TimeoutPhase::Proxy)?;
+        Self::negotiate_http_proxy(
+            &mut TimeoutStream::new(proxy_stream.as_mut(), timeout_error),
+            &target_host,
+            target_port,
+            proxy_config,
+        )?;
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if Self::get_protocol(&config).is_https() {
+            let tunnel_stream: SyncProxyTunnelStream = SyncProxyTunnelStream::new(proxy_stream);
+            let tls_stream: StreamOwned<ClientConnection, SyncProxyTunnelStream> = self
+                .connect_tls(
+                    tunnel_stream,
+                    self.get_tls_server_name(&target_host)?,
+                    self.get_tls_config()?,
+                )?;
+            connection_info.set_tls(&tls_stream.conn);
+            self.set_connection_info(connection_info);
+            return Ok(Box::new(tls_stream));
+        }
+        self.set_connection_info(connection_info);
+        Ok(proxy_stream)
+    }
+
+    /// Establishes a SOCKS5 proxy connection.
+    fn get_socks5_proxy_connection(
+        &self,
+        target_host: String,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+    ) -> Result<Box<dyn ReadWrite>, HttpRequestError> {
+        let mut tcp_stream: TcpStream = self.connect_tcp(&proxy_config.host, proxy_config.port)?;
+        let mut connection_info: ConnectionInfo =
+            ConnectionInfo::from_addrs(tcp_stream.peer_addr(), tcp_stream.local_addr());
+        connection_info.set_proxy(proxy_config);
+        let timeout_error: RequestTimeoutError =
+            self.set_socket_timeouts(&tcp_stream, TimeoutPhase::Proxy)?;
+        Self::negotiate_socks5_proxy(
+            &mut TimeoutStream::new(&mut tcp_stream, timeout_error),
+            &target_host,
+            target_port,
+            proxy_config,
+        )?;
+        let proxy_stream: Box<dyn ReadWrite> = Box::new(tcp_stream);
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if Self::get_protocol(&config).is_https() {
+            let tunnel_stream: SyncProxyTunnelStream = SyncProxyTunnelStream::new(proxy_stream);
+            let tls_stream: StreamOwned<ClientConnection, SyncProxyTunnelStream> = self
+                .connect_tls(
+                    tunnel_stream,
+                    self.get_tls_server_name(&target_host)?,
+                    self.get_tls_config()?,
+                )?;
+            connection_info.set_tls(&tls_stream.conn);
+            self.set_connection_info(connection_info);
+            return Ok(Box::new(tls_stream));
+        }
+        self.set_connection_info(connection_info);
+        Ok(proxy_stream)
+    }
+
+    /// Asks an HTTP proxy to open a tunnel to the target with `CONNECT`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut S` - The stream to the proxy.
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `&ProxyConfig` - The proxy configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - Ok once the proxy accepted the tunnel.
+    fn negotiate_http_proxy<S: Read + Write>(
+        stream: &mut S,
+        target_host: &str,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+    ) -> Result<(), HttpRequestError> {
         let connect_request: String = if let (Some(username), Some(password)) =
             (&proxy_config.username, &proxy_config.password)
         {
@@ -707,92 +1387,50 @@ impl HttpRequest {
                 target_host, target_port, target_host, target_port
             )
         };
-        proxy_stream
-            .write_all(connect_request.as_bytes())
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        proxy_stream
-            .flush()
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        stream.write_all(connect_request.as_bytes())?;
+        stream.flush()?;
         let mut response_buffer = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
-            .read(&mut response_buffer)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        let bytes_read: usize = stream.read(&mut response_buffer)?;
         let response: Cow<'_, str> = String::from_utf8_lossy(&response_buffer[..bytes_read]);
         if !response.starts_with("HTTP/1.1 200") && !response.starts_with("HTTP/1.0 200") {
             return Err(RequestError::Request(format!(
                 "Proxy connection failed: {}",
                 response.lines().next().unwrap_or("Unknown error")
-            )));
+            ))
+            .into());
         }
-        let config: Config = self
-            .config
-            .read()
-            .map_or(Config::default(), |config| config.clone());
-        if Self::get_protocol(&config).is_https() {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                        .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name)
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-
-                    let tunnel_stream = crate::request::SyncProxyTunnelStream::new(proxy_stream);
-                    let tls_stream: StreamOwned<
-                        ClientConnection,
-                        crate::request::SyncProxyTunnelStream,
-                    > = StreamOwned::new(session, tunnel_stream);
-                    return Ok(Box::new(tls_stream));
-                }
-                Err(err) => {
-                    return Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    )));
-                }
-            }
-        }
-
-        Ok(proxy_stream)
+        Ok(())
     }
 
-    /// Establishes a SOCKS5 proxy connection.
-    fn get_socks5_proxy_connection(
-        &self,
-        target_host: String,
+    /// Authenticates with a SOCKS5 proxy and asks it to connect to the target.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut S` - The stream to the proxy.
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `&ProxyConfig` - The proxy configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - Ok once the proxy connected to the target.
+    fn negotiate_socks5_proxy<S: Read + Write>(
+        stream: &mut S,
+        target_host: &str,
         target_port: u16,
         proxy_config: &ProxyConfig,
-        timeout: Duration,
-    ) -> Result<Box<dyn ReadWrite>, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: TcpStream = TcpStream::connect(proxy_host_port)
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
-        tcp_stream
-            .set_read_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetReadTimeout(err.to_string()))?;
-        tcp_stream
-            .set_write_timeout(Some(timeout))
-            .map_err(|err| RequestError::SetWriteTimeout(err.to_string()))?;
+    ) -> Result<(), HttpRequestError> {
         let auth_methods: Vec<u8> =
             if proxy_config.username.is_some() && proxy_config.password.is_some() {
                 vec![0x05, 0x02, 0x00, 0x02]
             } else {
                 vec![0x05, 0x01, 0x00]
             };
-        tcp_stream
-            .write_all(&auth_methods)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        stream.write_all(&auth_methods)?;
         let mut response = [0u8; 2];
-        tcp_stream
-            .read_exact(&mut response)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        stream.read_exact(&mut response)?;
         if response[0] != 0x05 {
-            return Err(RequestError::Request("Invalid SOCKS5 response".to_string()));
+            return Err(RequestError::Request("Invalid SOCKS5 response".to_string()).into());
         }
         match response[1] {
             0x00 => {}
@@ -805,33 +1443,33 @@ impl HttpRequest {
                     auth_request.extend_from_slice(username.as_bytes());
                     auth_request.push(password.len() as u8);
                     auth_request.extend_from_slice(password.as_bytes());
-                    tcp_stream
-                        .write_all(&auth_request)
-                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                    stream.write_all(&auth_request)?;
                     let mut auth_response: [u8; 2] = [0u8; 2];
-                    tcp_stream
-                        .read_exact(&mut auth_response)
-                        .map_err(|err| RequestError::Request(err.to_string()))?;
+                    stream.read_exact(&mut auth_response)?;
                     if auth_response[1] != 0x00 {
                         return Err(RequestError::Request(
                             "SOCKS5 authentication failed".to_string(),
-                        ));
+                        )
+                        .into());
                     }
                 } else {
                     return Err(RequestError::Request(
                         "SOCKS5 proxy requires authentication".to_string(),
-                    ));
+                    )
+                    .into());
                 }
             }
             0xFF => {
                 return Err(RequestError::Request(
                     "No acceptable SOCKS5 authentication methods".to_string(),
-                ));
+                )
+                .into());
             }
             _ => {
                 return Err(RequestError::Request(
                     "Unsupported SOCKS5 authentication method".to_string(),
-                ));
+                )
+                .into());
             }
         }
         let mut connect_request: Vec<u8> = vec![0x05, 0x01, 0x00];
@@ -849,83 +1487,38 @@ impl HttpRequest {
             connect_request.extend_from_slice(target_host.as_bytes());
         }
         connect_request.extend_from_slice(&target_port.to_be_bytes());
-        tcp_stream
-            .write_all(&connect_request)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        stream.write_all(&connect_request)?;
         let mut connect_response: [u8; 4] = [0u8; 4];
-        tcp_stream
-            .read_exact(&mut connect_response)
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        stream.read_exact(&mut connect_response)?;
         if connect_response[0] != 0x05 || connect_response[1] != 0x00 {
             return Err(RequestError::Request(format!(
                 "SOCKS5 connection failed with code: {}",
                 connect_response[1]
-            )));
+            ))
+            .into());
         }
         match connect_response[3] {
             0x01 => {
                 let mut skip = [0u8; 6];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                stream.read_exact(&mut skip)?;
             }
             0x03 => {
                 let mut len = [0u8; 1];
-                tcp_stream
-                    .read_exact(&mut len)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                stream.read_exact(&mut len)?;
                 let mut skip = vec![0u8; len[0] as usize + 2];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                stream.read_exact(&mut skip)?;
             }
             0x04 => {
                 let mut skip = [0u8; 18];
-                tcp_stream
-                    .read_exact(&mut skip)
-                    .map_err(|err| RequestError::Request(err.to_string()))?;
+                stream.read_exact(&mut skip)?;
             }
             _ => {
-                return Err(RequestError::Request(
-                    "Invalid SOCKS5 address type".to_string(),
-                ));
+                return Err(
+                    RequestError::Request("Invalid SOCKS5 address type".to_string()).into(),
+                );
             }
         }
-        let proxy_stream: Box<dyn ReadWrite> = Box::new(tcp_stream);
-        let config: Config = self
-            .config
-            .read()
-            .map_or(Config::default(), |config| config.clone());
-        if Self::get_protocol(&config).is_https() {
-            match self.tmp.clone().read() {
-                Ok(tmp) => {
-                    let roots: RootCertStore = tmp.root_cert.clone();
-                    let tls_config: ClientConfig = ClientConfig::builder()
-                        .with_root_certificates(roots)
-                        .with_no_client_auth();
-                    let client_config: Arc<ClientConfig> = Arc::new(tls_config);
-                    let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                        .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-                    let session: ClientConnection =
-                        ClientConnection::new(Arc::clone(&client_config), dns_name)
-                            .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-
-                    let tunnel_stream = crate::request::SyncProxyTunnelStream::new(proxy_stream);
-                    let tls_stream: StreamOwned<
-                        ClientConnection,
-                        crate::request::SyncProxyTunnelStream,
-                    > = StreamOwned::new(session, tunnel_stream);
-                    return Ok(Box::new(tls_stream));
-                }
-                Err(err) => {
-                    return Err(RequestError::Unknown(format!(
-                        "error reading temporary configuration: {}",
-                        err
-                    )));
-                }
-            }
-        }
-        Ok(proxy_stream)
+        Ok(())
     }
 }
 
@@ -942,217 +1535,268 @@ impl HttpRequest {
             host = config.url_obj.host.clone().unwrap_or_default();
             port = self.get_port(config.url_obj.port.clone().unwrap_or_default(), &config);
         }
-        let mut stream: BoxReadWrite = self.get_connection_stream(host, port)?;
-        let res: Result<BoxResponseTrait, RequestError> = match methods {
-            m if m.is_get() => self.send_get_request(&mut stream),
-            m if m.is_post() => self.send_post_request(&mut stream),
-            err => Err(RequestError::Request(format!(
-                "do not support {} method",
-                err
-            ))),
+        if !SharedRequestBuilder::is_valid_method(&methods.to_string()) {
+            return Err(RequestError::MethodsNotSupport.into());
+        }
+        self.check_body_error()?;
+        if self.is_http2() {
+            let runtime: Arc<SyncRuntime> = self
+                .get_pool_key()
+                .map_or_else(Arc::default, |(pool, _)| pool.runtime);
+            let mut request: HttpRequest = self.clone();
+            return runtime.run(async move { request.send_http2_async(host, port).await })?;
+        }
+        let Some((pool, key)) = self.get_pool_key() else {
+            let stream: BoxReadWrite = self.get_connection_stream(host, port)?;
+            return self.send_request(stream);
         };
-        res
+        loop {
+            let (timeout_error, duration) = self.get_phase_timeout(TimeoutPhase::Connect)?;
+            match pool.checkout_sync(&key, duration).ok_or(timeout_error)? {
+                PoolCheckout::Idle(stream, connection_info) => {
+                    self.set_connection_info(connection_info);
+                    if let Some(result) = self.send_pooled_request(stream) {
+                        return result;
+                    }
+                }
+                PoolCheckout::Open(permit) => {
+                    let stream: BoxReadWrite = Box::new(PooledStream {
+                        stream: self.get_connection_stream(host, port)?,
+                        _permit: *permit,
+                    });
+                    return self.send_request(stream);
+                }
+            }
+        }
     }
 }
 
 /// Async implementation for HttpRequest
 impl HttpRequest {
-    /// Sends an async GET request.
+    /// Sends an async request for the configured method.
     ///
     /// # Arguments
     ///
-    /// - `&mut BoxAsyncReadWrite` - The async stream to write to.
+    /// - `BoxAsyncReadWrite` - The async stream to write to, returned to the connection pool
+    ///   when it can be reused.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxResponseTrait, RequestError>` - Result containing the response or error.
-    async fn send_get_request_async(
+    /// - `Result<BoxResponseTrait, HttpRequestError>` - Result containing the response or error.
+    async fn send_request_async(
         &mut self,
-        stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let request: Vec<u8> =
-            SharedRequestBuilder::build_get_request(path, header_bytes, http_version_str);
-        stream
-            .write_all(&request)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        stream
-            .flush()
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
+        mut stream: BoxAsyncReadWrite,
+    ) -> Result<BoxResponseTrait, HttpRequestError> {
+        self.write_request_async(&mut stream).await?;
         self.read_response_async(stream).await
     }
 
-    /// Sends an async POST request.
+    /// Sends an async request over an idle connection taken from the pool.
     ///
     /// # Arguments
     ///
-    /// - `&mut BoxAsyncReadWrite` - The async stream to write to.
+    /// - `BoxAsyncReadWrite` - The pooled stream.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxResponseTrait, RequestError>` - Result containing the response or error.
-    async fn send_post_request_async(
+    /// - `Option<RequestResult>` - The result, or `None` when the server had already closed the
+    ///   connection before sending anything, in which case the request can be retried.
+    async fn send_pooled_request_async(
         &mut self,
+        mut stream: BoxAsyncReadWrite,
+    ) -> Option<RequestResult> {
+        if self.write_request_async(&mut stream).await.is_err() {
+            return None;
+        }
+        match self.receive_response_async(&mut stream).await {
+            Ok((parser, closed)) if closed && parser.is_empty() => None,
+            Ok((parser, _)) => {
+                self.release_connection_async(stream, &parser);
+                Some(match parser.finish() {
+                    Ok((response_bytes, redirect_url)) => {
+                        self.handle_response_async(response_bytes, redirect_url)
+                            .await
+                    }
+                    Err(err) => Err(err.into()),
+                })
+            }
+            Err(err) => Some(Err(err)),
+        }
+    }
+
+    /// Writes the request line, headers and body to the async stream.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The async stream to write to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - Ok when the whole request was written.
+    async fn write_request_async(
+        &self,
         stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
-        let path: String = self.get_path();
-        let header_bytes: Vec<u8> = self.get_header_bytes();
-        let body_bytes: Vec<u8> = self.get_body_bytes();
-        let http_version_str: String =
-            self.config.read().map_or("HTTP/1.1".to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let request: Vec<u8> = SharedRequestBuilder::build_post_request(
-            path,
-            header_bytes,
-            body_bytes,
-            http_version_str,
-        );
-        stream
-            .write_all(&request)
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        stream
-            .flush()
-            .await
-            .map_err(|err| RequestError::Request(err.to_string()))?;
-        self.read_response_async(stream).await
+    ) -> Result<(), HttpRequestError> {
+        let request: Vec<u8> = self.get_request_bytes()?;
+        self.with_timeout(TimeoutPhase::Write, async {
+            stream
+                .write_all(&request)
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+            if let Some(multipart) = self.get_streamed_body() {
+                multipart.write_to_async(stream.as_mut()).await?;
+            }
+            stream
+                .flush()
+                .await
+                .map_err(|err| RequestError::Request(err.to_string()))
+        })
+        .await
     }
 
     /// Reads an async HTTP response.
     ///
     /// # Arguments
     ///
-    /// - `&mut BoxAsyncReadWrite` - The async stream to read from.
+    /// - `BoxAsyncReadWrite` - The async stream to read from.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxResponseTrait, RequestError>` - Result containing the response or error.
+    /// - `Result<BoxResponseTrait, HttpRequestError>` - Result containing the response or error.
     async fn read_response_async(
         &mut self,
+        mut stream: BoxAsyncReadWrite,
+    ) -> Result<BoxResponseTrait, HttpRequestError> {
+        let (parser, _) = self.receive_response_async(&mut stream).await?;
+        self.release_connection_async(stream, &parser);
+        let (response_bytes, redirect_url) = parser.finish()?;
+        self.handle_response_async(response_bytes, redirect_url)
+            .await
+    }
+
+    /// Reads from the async stream until the response is complete or the connection ends.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The async stream to read from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(ResponseParser, bool), HttpRequestError>` - The parser holding the response, and
+    ///   whether reading stopped because the server closed the connection.
+    async fn receive_response_async(
+        &self,
         stream: &mut BoxAsyncReadWrite,
-    ) -> Result<BoxResponseTrait, RequestError> {
+    ) -> Result<(ResponseParser, bool), HttpRequestError> {
         let buffer_size: usize = self
             .config
             .read()
             .map_or(DEFAULT_BUFFER_SIZE, |config| config.buffer);
         let mut buffer: Vec<u8> = vec![0; buffer_size];
-        let initial_capacity: usize = buffer_size.max(8192);
-        let mut response_bytes: Vec<u8> = Vec::with_capacity(initial_capacity);
-        let mut headers_done: bool = false;
-        let mut content_length: usize = 0;
-        let mut redirect_url: Option<Vec<u8>> = None;
-        let mut headers_end_pos: usize = 0;
-        let http_version: String = self
-            .config
-            .read()
-            .map_or(HttpVersion::default().to_string(), |config| {
-                config.http_version.to_string()
-            });
-        let http_version_bytes: Vec<u8> = http_version.to_lowercase().into_bytes();
-        let location_sign_key: Vec<u8> = format!("{}:", LOCATION.to_lowercase()).into_bytes();
-        'read_loop: loop {
-            let n: usize = stream
-                .read(&mut buffer)
-                .await
-                .map_err(|err| RequestError::Request(err.to_string()))?;
-            if n == 0 {
-                break;
-            }
-            let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
-                &response_bytes,
-                n,
-                response_bytes.capacity(),
-            );
-            if new_capacity > 0 {
-                response_bytes.reserve(new_capacity - response_bytes.capacity());
+        let mut parser: ResponseParser = ResponseParser::new(self.get_methods(), buffer_size);
+        let mut low_speed: Option<LowSpeedTracker> = self.get_low_speed_tracker();
+        loop {
+            let started: Instant = Instant::now();
+            let read_result: Option<io::Result<usize>> = self
+                .read_within_window(low_speed.as_ref(), stream.read(&mut buffer))
+                .await?;
+            if let Some(tracker) = &mut low_speed {
+                let received: usize = read_result
+                    .as_ref()
+                    .and_then(|result| result.as_ref().ok())
+                    .copied()
+                    .unwrap_or_default();
+                tracker.record(received, started.elapsed())?;
             }
-            let old_len: usize = response_bytes.len();
-            response_bytes.extend_from_slice(&buffer[..n]);
-            if !headers_done {
-                let search_start: usize = old_len.saturating_sub(3);
-                if let Some(pos) =
-                    SharedResponseHandler::find_double_crlf(&response_bytes, search_start)
+            let Some(read_result) = read_result else {
+                continue;
+            };
+            let n: usize = match read_result {
+                Ok(n) => n,
+                Err(err)
+                    if parser.is_empty()
+                        && SharedResponseHandler::is_connection_closed(err.kind()) =>
                 {
-                    headers_done = true;
-                    headers_end_pos = pos + 4;
-                    SharedResponseHandler::parse_response_headers(
-                        &response_bytes[..headers_end_pos],
-                        &http_version_bytes,
-                        &location_sign_key,
-                        &mut content_length,
-                        &mut redirect_url,
-                    )?;
+                    return Ok((parser, true));
                 }
+                Err(err) => return Err(RequestError::Request(err.to_string()).into()),
+            };
+            if n == 0 {
+                return Ok((parser, true));
             }
-            if headers_done {
-                let total_expected_length: usize = headers_end_pos + content_length;
-                if response_bytes.len() >= total_expected_length {
-                    response_bytes.truncate(total_expected_length);
-                    break 'read_loop;
-                }
+            if parser.feed(&buffer[..n])? {
+                return Ok((parser, false));
             }
         }
-        self.response = Arc::new(RwLock::new(<HttpResponseBinary as ResponseTrait>::from(
-            &response_bytes,
-        )));
-        let (should_redirect, should_decode, buffer_size) = {
-            if let Ok(config) = self.config.read() {
-                (config.redirect, config.decode, config.buffer)
-            } else {
-                (false, false, DEFAULT_BUFFER_SIZE)
-            }
-        };
-        if !should_redirect || redirect_url.is_none() {
-            if should_decode {
-                if let Ok(mut response) = self.response.write() {
-                    *response = response.decode(buffer_size);
-                }
-            }
-            return Ok(Box::new(
+    }
+
+    /// Returns the async stream to the connection pool when the response left it reusable.
+    ///
+    /// # Arguments
+    ///
+    /// - `BoxAsyncReadWrite` - The async stream the response was read from.
+    /// - `&ResponseParser` - The parser holding the response.
+    fn release_connection_async(&self, stream: BoxAsyncReadWrite, parser: &ResponseParser) {
+        if parser.is_reusable()
+            && let Some((pool, key)) = self.get_pool_key()
+        {
+            pool.put_async(key, stream, self.get_connection_info());
+        }
+    }
+
+    /// Builds the async response and follows a redirect if one was requested.
+    ///
+    /// # Arguments
+    ///
+    /// - `Vec<u8>` - The raw bytes of the complete response.
+    /// - `Option<Vec<u8>>` - The redirect URL found in the response, if any.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxResponseTrait, HttpRequestError>` - The final response or an error.
+    async fn handle_response_async(
+        &mut self,
+        response_bytes: Vec<u8>,
+        redirect_url: Option<Vec<u8>>,
+    ) -> Result<BoxResponseTrait, HttpRequestError> {
+        match self.build_response(response_bytes, redirect_url)? {
+            Some(url) => self.handle_redirect_async(url).await,
+            None => Ok(Box::new(
                 self.response
                     .read()
                     .map_or(HttpResponseBinary::default(), |response| response.clone()),
-            ));
+            )),
         }
-        let url: String = String::from_utf8(redirect_url.unwrap())
-            .map_err(|err| RequestError::InvalidUrl(err.to_string()))?;
-        self.handle_redirect_async(url).await
     }
 
     /// Handles async HTTP redirects.
     ///
     /// # Arguments
     ///
-    /// - `String` - The redirect URL.
+    /// - `String` - The redirect URL, resolved against the current URL when relative so that the
+    ///   cookie jar matches cookies against the host and path actually requested next.
     ///
     /// # Returns
     ///
-    /// - `Pin<Box<dyn Future<Output = Result<BoxResponseTrait, RequestError>> + Send + '_>>` - Future representing the redirect handling.
+    /// - `Pin<Box<dyn Future<Output = Result<BoxResponseTrait, HttpRequestError>> + Send + '_>>` - Future representing the redirect handling.
     fn handle_redirect_async(
         &mut self,
         url: String,
-    ) -> Pin<Box<dyn Future<Output = Result<BoxResponseTrait, RequestError>> + Send + '_>> {
+    ) -> Pin<Box<dyn Future<Output = Result<BoxResponseTrait, HttpRequestError>> + Send + '_>> {
         Box::pin(async move {
+            let url: String = resolve_url(&self.get_url(), &url);
             {
                 if let Ok(mut config) = self.config.write() {
                     if !config.redirect {
-                        return Err(RequestError::NeedOpenRedirect);
+                        return Err(RequestError::NeedOpenRedirect.into());
                     }
                     if let Ok(mut tmp) = self.tmp.clone().write() {
                         if tmp.visit_url.contains(&url) {
-                            return Err(RequestError::RedirectUrlDeadLoop);
+                            return Err(RequestError::RedirectUrlDeadLoop.into());
                         }
                         tmp.visit_url.insert(url.clone());
                         if config.redirect_times >= config.max_redirect_times {
-                            return Err(RequestError::MaxRedirectTimes);
+                            return Err(RequestError::MaxRedirectTimes.into());
                         }
                         config.redirect_times += 1;
                     }
@@ -1165,6 +1809,8 @@ impl HttpRequest {
 
     /// Establishes an async connection stream.
     ///
+    /// The details of the connection are recorded so they can be exposed on the response.
+    ///
     /// # Arguments
     ///
     /// - `String` - The host to connect to.
@@ -1172,12 +1818,12 @@ impl HttpRequest {
     ///
     /// # Returns
     ///
-    /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
+    /// - `Result<BoxAsyncReadWrite, HttpRequestError>` - Result containing the stream or error.
     async fn get_connection_stream_async(
         &self,
         host: String,
         port: u16,
-    ) -> Result<BoxAsyncReadWrite, RequestError> {
+    ) -> Result<BoxAsyncReadWrite, HttpRequestError> {
         let config: Config = self
             .config
             .read()
@@ -1188,33 +1834,23 @@ impl HttpRequest {
                 .await;
         }
         let host_port: (String, u16) = (host.clone(), port);
-        let tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(host_port.clone())
-            .await
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
+        let tcp_stream: AsyncTcpStream = self.connect_tcp_async(host_port).await?;
+        let mut connection_info: ConnectionInfo =
+            ConnectionInfo::from_addrs(tcp_stream.peer_addr(), tcp_stream.local_addr());
         if Self::get_protocol(&config).is_https() {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tls_stream: TlsStream<AsyncTcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+            let tls_stream: TlsStream<AsyncTcpStream> = self
+                .connect_tls_async(
+                    tcp_stream,
+                    self.get_tls_server_name(&host)?,
+                    self.get_alpn_tls_config()?,
+                )
+                .await?;
+            self.check_alpn_protocol(tls_stream.get_ref().1.alpn_protocol())?;
+            connection_info.set_tls(tls_stream.get_ref().1);
+            self.set_connection_info(connection_info);
             Ok(Box::new(tls_stream))
         } else {
+            self.set_connection_info(connection_info);
             Ok(Box::new(tcp_stream))
         }
     }
@@ -1229,13 +1865,13 @@ impl HttpRequest {
     ///
     /// # Returns
     ///
-    /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
+    /// - `Result<BoxAsyncReadWrite, HttpRequestError>` - Result containing the stream or error.
     async fn get_proxy_connection_stream_async(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
-    ) -> Result<BoxAsyncReadWrite, RequestError> {
+    ) -> Result<BoxAsyncReadWrite, HttpRequestError> {
         match proxy_config.proxy_type {
             ProxyType::Http | ProxyType::Https => {
                 self.get_http_proxy_connection_async(target_host, target_port, proxy_config)
@@ -1258,43 +1894,135 @@ impl HttpRequest {
     ///
     /// # Returns
     ///
-    /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
+    /// - `Result<BoxAsyncReadWrite, HttpRequestError>` - Result containing the stream or error.
     async fn get_http_proxy_connection_async(
         &self,
         target_host: String,
         target_port: u16,
         proxy_config: &ProxyConfig,
-    ) -> Result<BoxAsyncReadWrite, RequestError> {
+    ) -> Result<BoxAsyncReadWrite, HttpRequestError> {
         let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(proxy_host_port)
-            .await
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
+        let tcp_stream: AsyncTcpStream = self.connect_tcp_async(proxy_host_port).await?;
+        let mut connection_info: ConnectionInfo =
+            ConnectionInfo::from_addrs(tcp_stream.peer_addr(), tcp_stream.local_addr());
+        connection_info.set_proxy(proxy_config);
         let mut proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tls_stream: TlsStream<AsyncTcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
+            let tls_stream: TlsStream<AsyncTcpStream> = self
+                .connect_tls_async(
+                    tcp_stream,
+                    Self::get_proxy_server_name(proxy_config)?,
+                    self.get_proxy_tls_config()?,
+                )
+                .await?;
             Box::new(tls_stream)
         } else {
             Box::new(tcp_stream)
         };
+        self.with_timeout(
+            TimeoutPhase::Proxy,
+            Self::negotiate_http_proxy_async(
+                &mut proxy_stream,
+                &target_host,
+                target_port,
+                proxy_config,
+            ),
+        )
+        .await?;
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if Self::get_protocol(&config).is_https() {
+            let tunnel_stream: ProxyTunnelStream = ProxyTunnelStream::new(proxy_stream);
+            let tls_stream: TlsStream<ProxyTunnelStream> = self
+                .connect_tls_async(
+                    tunnel_stream,
+                    self.get_tls_server_name(&target_host)?,
+                    self.get_alpn_tls_config()?,
+                )
+                .await?;
+            self.check_alpn_protocol(tls_stream.get_ref().1.alpn_protocol())?;
+            connection_info.set_tls(tls_stream.get_ref().1);
+            self.set_connection_info(connection_info);
+            return Ok(Box::new(tls_stream));
+        }
+        self.set_connection_info(connection_info);
+        Ok(proxy_stream)
+    }
+
+    /// Establishes an async SOCKS5 proxy connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The target host.
+    /// - `u16` - The target port.
+    /// - `&ProxyConfig` - The proxy configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<BoxAsyncReadWrite, HttpRequestError>` - Result containing the stream or error.
+    async fn get_socks5_proxy_connection_async(
+        &self,
+        target_host: String,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+    ) -> Result<BoxAsyncReadWrite, HttpRequestError> {
+        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
+        let mut tcp_stream: AsyncTcpStream = self.connect_tcp_async(proxy_host_port).await?;
+        let mut connection_info: ConnectionInfo =
+            ConnectionInfo::from_addrs(tcp_stream.peer_addr(), tcp_stream.local_addr());
+        connection_info.set_proxy(proxy_config);
+        self.with_timeout(
+            TimeoutPhase::Proxy,
+            Self::negotiate_socks5_proxy_async(
+                &mut tcp_stream,
+                &target_host,
+                target_port,
+                proxy_config,
+            ),
+        )
+        .await?;
+        let proxy_stream: BoxAsyncReadWrite = Box::new(tcp_stream);
+        let config: Config = self
+            .config
+            .read()
+            .map_or(Config::default(), |config| config.clone());
+        if Self::get_protocol(&config).is_https() {
+            let tunnel_stream: ProxyTunnelStream = ProxyTunnelStream::new(proxy_stream);
+            let tls_stream: TlsStream<ProxyTunnelStream> = self
+                .connect_tls_async(
+                    tunnel_stream,
+                    self.get_tls_server_name(&target_host)?,
+                    self.get_alpn_tls_config()?,
+                )
+                .await?;
+            self.check_alpn_protocol(tls_stream.get_ref().1.alpn_protocol())?;
+            connection_info.set_tls(tls_stream.get_ref().1);
+            self.set_connection_info(connection_info);
+            return Ok(Box::new(tls_stream));
+        }
+        self.set_connection_info(connection_info);
+        Ok(proxy_stream)
+    }
+
+    /// Asks an HTTP proxy to open a tunnel to the target with `CONNECT`.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut BoxAsyncReadWrite` - The stream to the proxy.
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `&ProxyConfig` - The proxy configuration.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), HttpRequestError>` - Ok once the proxy accepted the tunnel.
+    async fn negotiate_http_proxy_async(
+        stream: &mut BoxAsyncReadWrite,
+        target_host: &str,
+        target_port: u16,
+        proxy_config: &ProxyConfig,
+    ) -> Result<(), HttpRequestError> {
         let connect_request: String = if let (Some(username), Some(password)) =
             (&proxy_config.username, &proxy_config.password)
         {
@@ -1310,16 +2038,16 @@ impl HttpRequest {
                 target_host, target_port, target_host, target_port
             )
         };
-        proxy_stream
+        stream
             .write_all(connect_request.as_bytes())
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
-        proxy_stream
+        stream
             .flush()
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
         let mut response_buffer: [u8; 1024] = [0u8; 1024];
-        let bytes_read: usize = proxy_stream
+        let bytes_read: usize = stream
             .read(&mut response_buffer)
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
@@ -1328,80 +2056,47 @@ impl HttpRequest {
             return Err(RequestError::Request(format!(
                 "Proxy connection failed: {}",
                 response.lines().next().unwrap_or("Unknown error")
-            )));
-        }
-        let config: Config = self
-            .config
-            .read()
-            .map_or(Config::default(), |config| config.clone());
-        if Self::get_protocol(&config).is_https() {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tunnel_stream: crate::request::ProxyTunnelStream =
-                crate::request::ProxyTunnelStream::new(proxy_stream);
-            let tls_stream: TlsStream<crate::request::ProxyTunnelStream> = connector
-                .connect(dns_name, tunnel_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            return Ok(Box::new(tls_stream));
+            ))
+            .into());
         }
-
-        Ok(proxy_stream)
+        Ok(())
     }
 
-    /// Establishes an async SOCKS5 proxy connection.
+    /// Authenticates with a SOCKS5 proxy and asks it to connect to the target.
     ///
     /// # Arguments
     ///
-    /// - `String` - The target host.
+    /// - `&mut AsyncTcpStream` - The stream to the proxy.
+    /// - `&str` - The target host.
     /// - `u16` - The target port.
     /// - `&ProxyConfig` - The proxy configuration.
     ///
     /// # Returns
     ///
-    /// - `Result<BoxAsyncReadWrite, RequestError>` - Result containing the stream or error.
-    async fn get_socks5_proxy_connection_async(
-        &self,
-        target_host: String,
+    /// - `Result<(), HttpRequestError>` - Ok once the proxy connected to the target.
+    async fn negotiate_socks5_proxy_async(
+        stream: &mut AsyncTcpStream,
+        target_host: &str,
         target_port: u16,
         proxy_config: &ProxyConfig,
-    ) -> Result<BoxAsyncReadWrite, RequestError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(proxy_host_port)
-            .await
-            .map_err(|err| RequestError::TcpStreamConnect(err.to_string()))?;
+    ) -> Result<(), HttpRequestError> {
         let auth_methods: Vec<u8> =
             if proxy_config.username.is_some() && proxy_config.password.is_some() {
                 vec![0x05, 0x02, 0x00, 0x02]
             } else {
                 vec![0x05, 0x01, 0x00]
             };
-        tcp_stream
+        stream
             .write_all(&auth_methods)
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
         let mut response: [u8; 2] = [0u8; 2];
-        tcp_stream
+        stream
             .read_exact(&mut response)
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
         if response[0] != 0x05 {
-            return Err(RequestError::Request("Invalid SOCKS5 response".to_string()));
+            return Err(RequestError::Request("Invalid SOCKS5 response".to_string()).into());
         }
         match response[1] {
             0x00 => {}
@@ -1415,13 +2110,13 @@ impl HttpRequest {
                     auth_request.push(password.len() as u8);
                     auth_request.extend_from_slice(password.as_bytes());
 
-                    tcp_stream
+                    stream
                         .write_all(&auth_request)
                         .await
                         .map_err(|err| RequestError::Request(err.to_string()))?;
 
                     let mut auth_response = [0u8; 2];
-                    tcp_stream
+                    stream
                         .read_exact(&mut auth_response)
                         .await
                         .map_err(|err| RequestError::Request(err.to_string()))?;
@@ -1429,23 +2124,27 @@ impl HttpRequest {
                     if auth_response[1] != 0x00 {
                         return Err(RequestError::Request(
                             "SOCKS5 authentication failed".to_string(),
-                        ));
+                        )
+                        .into());
                     }
                 } else {
                     return Err(RequestError::Request(
                         "SOCKS5 proxy requires authentication".to_string(),
-                    ));
+                    )
+                    .into());
                 }
             }
             0xFF => {
                 return Err(RequestError::Request(
                     "No acceptable SOCKS5 authentication methods".to_string(),
-                ));
+                )
+                .into());
             }
             _ => {
                 return Err(RequestError::Request(
                     "Unsupported SOCKS5 authentication method".to_string(),
-                ));
+                )
+                .into());
             }
         }
         let mut connect_request: Vec<u8> = vec![0x05, 0x01, 0x00];
@@ -1463,13 +2162,13 @@ impl HttpRequest {
             connect_request.extend_from_slice(target_host.as_bytes());
         }
         connect_request.extend_from_slice(&target_port.to_be_bytes());
-        tcp_stream
+        stream
             .write_all(&connect_request)
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
 
         let mut connect_response: [u8; 4] = [0u8; 4];
-        tcp_stream
+        stream
             .read_exact(&mut connect_response)
             .await
             .map_err(|err| RequestError::Request(err.to_string()))?;
@@ -1478,74 +2177,43 @@ impl HttpRequest {
             return Err(RequestError::Request(format!(
                 "SOCKS5 connection failed with code: {}",
                 connect_response[1]
-            )));
+            ))
+            .into());
         }
         match connect_response[3] {
             0x01 => {
                 let mut skip: [u8; 6] = [0u8; 6];
-                tcp_stream
+                stream
                     .read_exact(&mut skip)
                     .await
                     .map_err(|err| RequestError::Request(err.to_string()))?;
             }
             0x03 => {
                 let mut len: [u8; 1] = [0u8; 1];
-                tcp_stream
+                stream
                     .read_exact(&mut len)
                     .await
                     .map_err(|err| RequestError::Request(err.to_string()))?;
                 let mut skip: Vec<u8> = vec![0u8; len[0] as usize + 2];
-                tcp_stream
+                stream
                     .read_exact(&mut skip)
                     .await
                     .map_err(|err| RequestError::Request(err.to_string()))?;
             }
             0x04 => {
                 let mut skip: [u8; 18] = [0u8; 18];
-                tcp_stream
+                stream
                     .read_exact(&mut skip)
                     .await
                     .map_err(|err| RequestError::Request(err.to_string()))?;
             }
             _ => {
-                return Err(RequestError::Request(
-                    "Invalid SOCKS5 address type".to_string(),
-                ));
+                return Err(
+                    RequestError::Request("Invalid SOCKS5 address type".to_string()).into(),
+                );
             }
         }
-        let proxy_stream: BoxAsyncReadWrite = Box::new(tcp_stream);
-        let config: Config = self
-            .config
-            .read()
-            .map_or(Config::default(), |config| config.clone());
-        if Self::get_protocol(&config).is_https() {
-            let roots: RootCertStore = {
-                match self.tmp.clone().read() {
-                    Ok(tmp) => tmp.root_cert.clone(),
-                    Err(err) => {
-                        return Err(RequestError::Unknown(format!(
-                            "error reading temporary configuration: {}",
-                            err
-                        )));
-                    }
-                }
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(target_host.clone())
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            let tunnel_stream: crate::request::ProxyTunnelStream =
-                crate::request::ProxyTunnelStream::new(proxy_stream);
-            let tls_stream: TlsStream<crate::request::ProxyTunnelStream> = connector
-                .connect(dns_name, tunnel_stream)
-                .await
-                .map_err(|err| RequestError::TlsConnectorBuild(err.to_string()))?;
-            return Ok(Box::new(tls_stream));
-        }
-
-        Ok(proxy_stream)
+        Ok(())
     }
 
     /// Sends the HTTP request asynchronously.
@@ -1567,15 +2235,361 @@ impl HttpRequest {
                 (String::new(), 0u16)
             }
         };
-        let mut stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
-        let res: Result<BoxResponseTrait, RequestError> = match methods {
-            m if m.is_get() => self.send_get_request_async(&mut stream).await,
-            m if m.is_post() => self.send_post_request_async(&mut stream).await,
-            err => Err(RequestError::Request(format!(
-                "do not support {} method",
-                err
-            ))),
+        if !SharedRequestBuilder::is_valid_method(&methods.to_string()) {
+            return Err(RequestError::MethodsNotSupport.into());
+        }
+        self.check_body_error()?;
+        if self.is_http2() {
+            return self.send_http2_async(host, port).await;
+        }
+        let Some((pool, key)) = self.get_pool_key() else {
+            let stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
+            return self.send_request_async(stream).await;
+        };
+        loop {
+            let checkout: PoolCheckout<BoxAsyncReadWrite> = self
+                .with_timeout(TimeoutPhase::Connect, async {
+                    Ok::<PoolCheckout<BoxAsyncReadWrite>, HttpRequestError>(
+                        pool.checkout_async(&key).await,
+                    )
+                })
+                .await?;
+            match checkout {
+                PoolCheckout::Idle(stream, connection_info) => {
+                    self.set_connection_info(connection_info);
+                    if let Some(result) = self.send_pooled_request_async(stream).await {
+                        return result;
+                    }
+                }
+                PoolCheckout::Open(permit) => {
+                    let stream: BoxAsyncReadWrite = Box::new(PooledStream {
+                        stream: self.get_connection_stream_async(host, port).await?,
+                        _permit: *permit,
+                    });
+                    return self.send_request_async(stream).await;
+                }
+            }
+        }
+    }
+}
+
+/// HTTP/2 implementation for HttpRequest
+impl HttpRequest {
+    /// Checks whether the request is sent over HTTP/2.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether HTTP/2 was requested.
+    pub(crate) fn is_http2(&self) -> bool {
+        self.config
+            .read()
+            .is_ok_and(|config| config.http_version == HttpVersion::HTTP2)
+    }
+
+    /// Offers HTTP/2 through ALPN when it was requested.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut ClientConfig` - The TLS configuration of the connection to the target.
+    fn set_alpn_protocols(&self, tls_config: &mut ClientConfig) {
+        if self.is_http2() {
+            tls_config.alpn_protocols = vec![ALPN_H2.to_vec()];
+        }
+    }
+
+    /// Checks that the server agreed to HTTP/2 when it was requested.
+    ///
+    /// # Arguments
+    ///
+    /// - `Option<&[u8]>` - The ALPN protocol negotiated during the TLS handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - An error if HTTP/2 was requested but not negotiated.
+    fn check_alpn_protocol(&self, alpn_protocol: Option<&[u8]>) -> Result<(), RequestError> {
+        if self.is_http2() && alpn_protocol != Some(ALPN_H2) {
+            return Err(RequestError::Request(
+                "server did not negotiate HTTP/2 through ALPN".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Sends the request over HTTP/2.
+    ///
+    /// HTTPS connections negotiate HTTP/2 through ALPN, cleartext connections use prior
+    /// knowledge (h2c). Requests sent through a client share one connection per host, so
+    /// concurrent requests are multiplexed as separate streams.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The host to connect to.
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - Result of the request.
+    async fn send_http2_async(&mut self, host: String, port: u16) -> RequestResult {
+        let sender: SendRequest<Bytes> = match self.get_pool_key() {
+            Some((pool, key)) => {
+                self.get_pooled_http2_sender(&pool, &key, host, port)
+                    .await?
+            }
+            None => self.connect_http2(host, port).await?,
+        };
+        self.send_http2_request(sender).await
+    }
+
+    /// Gets the shared HTTP/2 connection of a client, opening it if needed.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ConnectionPool` - The connection pool of the client.
+    /// - `&PoolKey` - The key of the request.
+    /// - `String` - The host to connect to.
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<SendRequest<Bytes>, HttpRequestError>` - A handle ready to open a new stream.
+    async fn get_pooled_http2_sender(
+        &self,
+        pool: &ConnectionPool,
+        key: &PoolKey,
+        host: String,
+        port: u16,
+    ) -> Result<SendRequest<Bytes>, HttpRequestError> {
+        let slot: Http2Slot = pool.get_http2_slot(key);
+        let mut shared_sender: AsyncMutexGuard<'_, Option<(SendRequest<Bytes>, ConnectionInfo)>> =
+            slot.lock().await;
+        if let Some((sender, connection_info)) = shared_sender.clone()
+            && let Ok(sender) = sender.ready().await
+        {
+            self.set_connection_info(connection_info);
+            return Ok(sender);
+        }
+        let sender: SendRequest<Bytes> = self.connect_http2(host, port).await?;
+        *shared_sender = Some((sender.clone(), self.get_connection_info()));
+        Ok(sender)
+    }
+
+    /// Opens a connection and performs the HTTP/2 handshake.
+    ///
+    /// The connection is driven by a background task until the server or every handle closes it.
+    ///
+    /// # Arguments
+    ///
+    /// - `String` - The host to connect to.
+    /// - `u16` - The port to connect to.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<SendRequest<Bytes>, HttpRequestError>` - The handle used to open streams.
+    async fn connect_http2(
+        &self,
+        host: String,
+        port: u16,
+    ) -> Result<SendRequest<Bytes>, HttpRequestError> {
+        let stream: BoxAsyncReadWrite = self.get_connection_stream_async(host, port).await?;
+        let (sender, connection) = http2_handshake(stream)
+            .await
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        async_spawn(async move {
+            let _ = connection.await;
+        });
+        Ok(sender)
+    }
+
+    /// Sends the request as a new HTTP/2 stream and reads the response.
+    ///
+    /// # Arguments
+    ///
+    /// - `SendRequest<Bytes>` - The handle of the HTTP/2 connection.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestResult` - Result of the request.
+    async fn send_http2_request(&mut self, mut sender: SendRequest<Bytes>) -> RequestResult {
+        let request: Http2Request<()> = self.get_http2_request()?;
+        let body: Vec<u8> = self.get_http2_body().await?;
+        let (response, mut send_stream) = sender
+            .send_request(request, body.is_empty())
+            .map_err(|err| RequestError::Request(err.to_string()))?;
+        if !body.is_empty() {
+            self.with_timeout(
+                TimeoutPhase::Write,
+                Self::send_http2_body(&mut send_stream, Bytes::from(body)),
+            )
+            .await?;
+        }
+        let response: Http2Response<RecvStream> = self
+            .with_timeout(TimeoutPhase::Read, async {
+                response
+                    .await
+                    .map_err(|err| RequestError::Request(err.to_string()))
+            })
+            .await?;
+        let (response_bytes, redirect_url) = self.read_http2_response(response).await?;
+        self.handle_response_async(response_bytes, redirect_url)
+            .await
+    }
+
+    /// Builds the HTTP/2 request head.
+    ///
+    /// The target is sent in the `:scheme`, `:authority` and `:path` pseudo-headers, so `Host`
+    /// and the connection-specific headers of HTTP/1.1 are left out.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Http2Request<()>, RequestError>` - The request head or an error.
+    fn get_http2_request(&self) -> Result<Http2Request<()>, RequestError> {
+        let (scheme, authority) = self
+            .config
+            .read()
+            .map(|config| {
+                let host: String = config.url_obj.host.clone().unwrap_or_default();
+                let authority: String = match config.url_obj.port {
+                    Some(port) => format!("{}:{}", host, port),
+                    None => host,
+                };
+                (Self::get_protocol(&config).to_string(), authority)
+            })
+            .map_err(|err| RequestError::Unknown(err.to_string()))?;
+        let mut builder: Http2RequestBuilder = Http2Request::builder()
+            .method(self.get_methods().to_string().as_str())
+            .uri(format!("{}://{}{}", scheme, authority, self.get_path()))
+            .version(Http2Version::HTTP_2);
+        for (key, value) in self.get_request_headers()?.lines() {
+            if key.eq_ignore_ascii_case(HOST)
+                || HTTP2_CONNECTION_HEADERS
+                    .iter()
+                    .any(|name| key.eq_ignore_ascii_case(name))
+            {
+                continue;
+            }
+            builder = builder.header(key.as_str(), value.as_str());
+        }
+        builder
+            .body(())
+            .map_err(|err| RequestError::Request(err.to_string()))
+    }
+
+    /// Gets the body sent in the DATA frames of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Vec<u8>, RequestError>` - The body bytes, empty when the request has no content.
+    async fn get_http2_body(&self) -> Result<Vec<u8>, RequestError> {
+        match self.get_streamed_body() {
+            Some(multipart) => {
+                let mut body: Vec<u8> = Vec::with_capacity(multipart.get_content_length());
+                multipart.write_to_async(&mut body).await?;
+                Ok(body)
+            }
+            None => Ok(self.get_request_body_bytes()?.unwrap_or_default()),
+        }
+    }
+
+    /// Sends a request body, waiting for flow-control capacity granted by the server.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut SendStream<Bytes>` - The stream of the request.
+    /// - `Bytes` - The body.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(), RequestError>` - Ok once the whole body was queued and the stream ended.
+    async fn send_http2_body(
+        send_stream: &mut SendStream<Bytes>,
+        mut body: Bytes,
+    ) -> Result<(), RequestError> {
+        while !body.is_empty() {
+            send_stream.reserve_capacity(body.len());
+            let capacity: usize = match poll_fn(|cx| send_stream.poll_capacity(cx)).await {
+                Some(Ok(capacity)) => capacity,
+                Some(Err(err)) => return Err(RequestError::Request(err.to_string())),
+                None => {
+                    return Err(RequestError::Request(
+                        "HTTP/2 stream closed while sending the body".to_string(),
+                    ));
+                }
+            };
+            if capacity == 0 {
+                continue;
+            }
+            let chunk: Bytes = body.split_to(capacity.min(body.len()));
+            send_stream
+                .send_data(chunk, body.is_empty())
+                .map_err(|err| RequestError::Request(err.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Reads an HTTP/2 response, releasing flow-control capacity as data arrives.
+    ///
+    /// The response is rendered as an HTTP/1-style message with an `HTTP/2` status line so it
+    /// can be parsed like any other response.
+    ///
+    /// # Arguments
+    ///
+    /// - `Http2Response<RecvStream>` - The response head and body stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Option<Vec<u8>>), HttpRequestError>` - The raw response bytes and the
+    ///   redirect URL, if any.
+    async fn read_http2_response(
+        &self,
+        response: Http2Response<RecvStream>,
+    ) -> Result<(Vec<u8>, Option<Vec<u8>>), HttpRequestError> {
+        let (parts, mut body) = response.into_parts();
+        let status: StatusCode = parts.status;
+        let mut response_bytes: Vec<u8> = format!(
+            "{} {} {}",
+            HTTP_VERSION_2,
+            status.as_u16(),
+            status.canonical_reason().unwrap_or_default()
+        )
+        .into_bytes();
+        response_bytes.extend_from_slice(HTTP_BR_BYTES);
+        for (key, value) in parts.headers.iter() {
+            response_bytes.extend_from_slice(key.as_str().as_bytes());
+            response_bytes.extend_from_slice(b": ");
+            response_bytes.extend_from_slice(value.as_bytes());
+            response_bytes.extend_from_slice(HTTP_BR_BYTES);
+        }
+        response_bytes.extend_from_slice(HTTP_BR_BYTES);
+        let mut low_speed: Option<LowSpeedTracker> = self.get_low_speed_tracker();
+        loop {
+            let started: Instant = Instant::now();
+            let data: Option<Option<Result<Bytes, H2Error>>> = self
+                .read_within_window(low_speed.as_ref(), body.data())
+                .await?;
+            if let Some(tracker) = &mut low_speed {
+                let received: usize = match &data {
+                    Some(Some(Ok(chunk))) => chunk.len(),
+                    _ => 0,
+                };
+                tracker.record(received, started.elapsed())?;
+            }
+            let chunk: Result<Bytes, H2Error> = match data {
+                Some(Some(chunk)) => chunk,
+                Some(None) => break,
+                None => continue,
+            };
+            let chunk: Bytes = chunk.map_err(|err| RequestError::Request(err.to_string()))?;
+            let _ = body.flow_control().release_capacity(chunk.len());
+            response_bytes.extend_from_slice(&chunk);
+        }
+        let redirect_url: Option<Vec<u8>> = if status.is_redirection() {
+            parts
+                .headers
+                .get(LOCATION)
+                .map(|location| location.as_bytes().to_vec())
+        } else {
+            None
         };
-        res
+        Ok((response_bytes, redirect_url))
     }
 }
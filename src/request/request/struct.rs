@@ -9,9 +9,11 @@ pub(crate) struct HttpRequest {
     /// Target URL for the request.
     pub(crate) url: Arc<String>,
     /// HTTP request headers.
-    pub(crate) header: Arc<RequestHeaders>,
+    pub(crate) header: Arc<OrderedHeaders>,
     /// HTTP request body content.
     pub(crate) body: Arc<Body>,
+    /// Why the body set on the builder could not be built, reported when the request is sent.
+    pub(crate) body_error: Option<String>,
     /// Request configuration settings.
     pub(crate) config: ArcRwLock<Config>,
     /// Temporary storage for request processing.
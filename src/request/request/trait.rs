@@ -5,10 +5,17 @@ use crate::*;
 /// Provides a unified trait for asynchronous read/write operations.
 pub(crate) trait AsyncReadWrite: AsyncRead + AsyncWrite + Unpin + Send {}
 
-/// Combines Read and Write traits.
+/// Combines Read and Write traits with a Send bound.
 ///
-/// Provides a unified trait for synchronous read/write operations.
-pub(crate) trait ReadWrite: Read + Write {}
+/// Provides a unified trait for synchronous read/write operations over a TCP socket.
+pub(crate) trait ReadWrite: Read + Write + Send {
+    /// Gets the TCP socket the stream runs over, so its timeouts can be adjusted.
+    ///
+    /// # Returns
+    ///
+    /// - `&TcpStream` - The socket.
+    fn get_tcp_stream(&self) -> &TcpStream;
+}
 
 /// Asynchronous HTTP request trait.
 ///
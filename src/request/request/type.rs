@@ -1,7 +1,7 @@
 use crate::*;
 
 /// Result type for HTTP requests, containing either a response or error.
-pub type RequestResult = Result<BoxResponseTrait, RequestError>;
+pub type RequestResult = Result<BoxResponseTrait, HttpRequestError>;
 
 /// Boxed trait object for asynchronous HTTP requests.
 pub type BoxAsyncRequestTrait = Box<dyn AsyncRequestTrait<RequestResult = RequestResult>>;
@@ -14,3 +14,6 @@ pub(crate) type BoxAsyncReadWrite = Box<dyn AsyncReadWrite>;
 
 /// Boxed trait object for synchronous read/write streams.
 pub(crate) type BoxReadWrite = Box<dyn ReadWrite>;
+
+/// Result of resolving a host to socket addresses.
+pub(crate) type ResolveResult = io::Result<Vec<SocketAddr>>;
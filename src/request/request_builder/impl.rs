@@ -5,6 +5,8 @@ impl Default for RequestBuilder {
         Self {
             http_request: HttpRequest::default(),
             builder: HttpRequest::default(),
+            query: String::new(),
+            base_url: None,
         }
     }
 }
@@ -49,426 +51,1193 @@ impl RequestBuilder {
         self
     }
 
+    /// Sets the HTTP method to PUT and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn put(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::PUT);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to PATCH and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn patch(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::PATCH);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to DELETE and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn delete(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::DELETE);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to HEAD and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn head(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::HEAD);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to OPTIONS and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn options(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::OPTIONS);
+        self.url(url);
+        self
+    }
+
+    /// Sets the HTTP method to TRACE and the request URL.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn trace(&mut self, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(Method::TRACE);
+        self.url(url);
+        self
+    }
+
+    /// Sets an arbitrary HTTP method and the request URL.
+    ///
+    /// Custom methods such as `PROPFIND` or `MKCOL` can be passed as
+    /// `Method::UNKNOWN("PROPFIND".to_string())` or parsed with `"PROPFIND".parse::<Method>()`.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The HTTP method.
+    /// - `str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn method(&mut self, method: Method, url: &str) -> &mut Self {
+        self.http_request.methods = Arc::new(method);
+        self.url(url);
+        self
+    }
+
     /// Sets the request URL.
     ///
+    /// Relative URLs are resolved against the base URL of the client the builder was
+    /// created from.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The request URL.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    fn url(&mut self, url: &str) -> &mut Self {
+        let url: String = match &self.base_url {
+            Some(base_url) => resolve_url(base_url, url),
+            None => url.to_owned(),
+        };
+        self.http_request.url = Arc::new(url);
+        self
+    }
+
+    /// Appends query parameters to the request URL.
+    ///
+    /// Keys and values are percent-encoded as UTF-8 and merged with any query already
+    /// present in the URL; a fragment is kept at the end. The parameters are appended when the
+    /// request is built, so they may be added before or after the URL is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(K, V)]` - The query parameters.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn query<K: ToString, V: ToString>(&mut self, params: &[(K, V)]) -> &mut Self {
+        let query: String = encode_pairs(params);
+        if !self.query.is_empty() && !query.is_empty() {
+            self.query.push('&');
+        }
+        self.query.push_str(&query);
+        self
+    }
+
+    /// Appends the pending query parameters to the request URL.
+    fn apply_query(&mut self) {
+        if !self.query.is_empty() {
+            let url: String = append_query(&self.http_request.url, &self.query);
+            self.http_request.url = Arc::new(url);
+            self.query.clear();
+        }
+    }
+
+    /// Forces HTTP/1.1 protocol version.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn http1_1_only(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.http_version = HttpVersion::HTTP1_1;
+        }
+        self
+    }
+
+    /// Forces HTTP/2 protocol version.
+    ///
+    /// HTTPS requests negotiate `h2` through ALPN and fail if the server does not agree;
+    /// cleartext requests use HTTP/2 with prior knowledge (h2c).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn http2_only(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.http_version = HttpVersion::HTTP2;
+        }
+        self
+    }
+
+    /// Sets request headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `HashMapXxHash3_64<K, V>` - The headers to set.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn headers<K, V>(&mut self, header: HashMapXxHash3_64<K, V>) -> &mut Self
+    where
+        K: ToString,
+        V: ToString,
+    {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.http_request.header) {
+            for (key, value) in header {
+                tmp_header.set(key.to_string(), value.to_string());
+            }
+        }
+        self
+    }
+
+    /// Sets a request header, replacing any existing values with the same name.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The header name (must implement ToString).
+    /// - `V` - The header value (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn header<K: ToString, V: ToString>(&mut self, key: K, value: V) -> &mut Self {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.http_request.header) {
+            tmp_header.set(key.to_string(), value.to_string());
+        }
+        self
+    }
+
+    /// Appends a request header value, keeping any existing values with the same name.
+    ///
+    /// Every value is sent on its own header line, in the order it was added.
+    ///
+    /// # Arguments
+    ///
+    /// - `K` - The header name (must implement ToString).
+    /// - `V` - The header value (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn append_header<K: ToString, V: ToString>(&mut self, key: K, value: V) -> &mut Self {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.http_request.header) {
+            tmp_header.append(key.to_string(), value.to_string());
+        }
+        self
+    }
+
+    /// Removes every value of a request header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn remove_header(&mut self, key: &str) -> &mut Self {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.http_request.header) {
+            tmp_header.remove(key);
+        }
+        self
+    }
+
+    /// Sets JSON request body.
+    ///
+    /// Any JSON value is accepted, including arrays and scalars.
+    ///
+    /// # Arguments
+    ///
+    /// - `JsonValue` - The JSON body data.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn json(&mut self, body: JsonValue) -> &mut Self {
+        self.set_body(Body::Json(body))
+    }
+
+    /// Sets JSON request body by serializing a typed value.
+    ///
+    /// If the value cannot be represented as JSON, sending the request fails with
+    /// `RequestError::Request` naming the serialization error, unless another body is set first.
+    ///
+    /// # Arguments
+    ///
+    /// - `&T` - The value to serialize (must implement Serialize).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn json_serde<T: Serialize>(&mut self, body: &T) -> &mut Self {
+        match json_to_value(body) {
+            Ok(json) => self.set_body(Body::Json(json)),
+            Err(err) => {
+                self.http_request.body_error =
+                    Some(format!("failed to serialize JSON body: {}", err));
+                self
+            }
+        }
+    }
+
+    /// Sets plain text request body.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The text body data (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn text<T: ToString>(&mut self, body: T) -> &mut Self {
+        self.set_body(Body::Text(body.to_string()))
+    }
+
+    /// Sets binary request body.
+    ///
+    /// # Arguments
+    ///
+    /// - `T` - The binary body data (must implement Into<Vec<u8>>).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn body<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
+        self.set_body(Body::Binary(body.into()))
+    }
+
+    /// Sets a URL-encoded form request body.
+    ///
+    /// Keys and values are percent-encoded as UTF-8 and the body is sent as
+    /// `application/x-www-form-urlencoded` unless another `Content-Type` is set.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[(K, V)]` - The form fields.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn form<K: ToString, V: ToString>(&mut self, fields: &[(K, V)]) -> &mut Self {
+        self.set_body(Body::Form(encode_pairs(fields)))
+    }
+
+    /// Sets a multipart form request body.
+    ///
+    /// The `Content-Type` with the form boundary and the `Content-Length` are set
+    /// automatically, and file parts are streamed from disk when the request is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Multipart` - The multipart form.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn multipart(&mut self, form: &Multipart) -> &mut Self {
+        self.set_body(Body::Multipart(form.clone()))
+    }
+
+    /// Replaces the body, discarding the error of a body that could not be built.
+    ///
+    /// # Arguments
+    ///
+    /// - `Body` - The new body.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    fn set_body(&mut self, body: Body) -> &mut Self {
+        self.http_request.body = Arc::new(body);
+        self.http_request.body_error = None;
+        self
+    }
+
+    /// Attaches a cookie jar to the request.
+    ///
+    /// Matching cookies are sent with the request and any redirects it follows, and
+    /// `Set-Cookie` headers of every response are stored in the jar. The jar is shared,
+    /// so the same jar can be attached to later requests. `Secure` cookies received over
+    /// `http` are dropped, so they are only stored from `https` responses.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CookieJar` - The cookie jar.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn cookie_jar(&mut self, cookie_jar: &CookieJar) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.cookie_jar = Some(cookie_jar.clone());
+        }
+        self
+    }
+
+    /// Sends the request through a client, reusing its idle keep-alive connections.
+    ///
+    /// Connections are only reused for the same scheme, host, port and proxy, and are
+    /// returned to the client's pool once the response has been read completely.
+    ///
+    /// # Arguments
+    ///
+    /// - `&Client` - The client.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn client(&mut self, client: &Client) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.pool = Some(client.pool.clone());
+        }
+        self
+    }
+
+    /// Sets the timeout value for the current connection.
+    ///
+    /// It applies to connecting, each write and each read, unless those have their own timeout.
+    /// A phase that runs out of time fails with a `RequestTimeoutError` naming it.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout duration in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.timeout = timeout;
+        }
+        self
+    }
+
+    /// Sets the timeout for resolving the host and connecting, TLS handshake and proxy
+    /// negotiation included.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout duration in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn connect_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.connect_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Sets how long each read of the response may wait for data.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout duration in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn read_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.read_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Sets the timeout for writing the request.
+    ///
+    /// Synchronous requests apply it to each write, asynchronous requests to the whole request.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The timeout duration in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn write_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.write_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Sets a deadline for the whole request, redirects included, starting when it is sent.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The deadline in milliseconds.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn total_timeout(&mut self, timeout: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.total_timeout = Some(timeout);
+        }
+        self
+    }
+
+    /// Aborts the request when the response arrives slower than a minimum speed.
+    ///
+    /// The speed is averaged over each `time` milliseconds spent waiting for the response, and
+    /// the request fails with a `LowSpeedError` when it falls below `bytes_per_second`.
+    ///
+    /// # Arguments
+    ///
+    /// - `u64` - The minimum speed in bytes per second.
+    /// - `u64` - The time in milliseconds the speed is averaged over.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn low_speed_limit(&mut self, bytes_per_second: u64, time: u64) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.low_speed_limit = Some(LowSpeedLimit::new(bytes_per_second, time));
+        }
+        self
+    }
+
+    /// Enables HTTP redirection for the request.
+    ///
+    /// This method sets the `redirect` property of the `http_request` to `true`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn redirect(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.redirect = true;
+        }
+        self
+    }
+
+    /// Unenables HTTP redirection for the request.
+    ///
+    /// This method sets the `redirect` property of the `http_request` to `false`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - The builder for method chaining.
+    pub fn unredirect(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.redirect = false;
+        };
+        self
+    }
+
+    /// Sets the maximum number of allowed redirections for the HTTP request.
+    ///
+    /// This method updates the `max_redirect_times` field in the configuration and returns a mutable
+    /// reference to `self` to enable method chaining.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The maximum number of redirections allowed. A value of `0` disables redirection.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance for method chaining.
+    ///
+    /// # Notes
+    ///
+    /// Ensure that the value provided to `num` is within a valid range. Excessively high values
+    /// may lead to performance issues or unintended behavior.
+    pub fn max_redirect_times(&mut self, num: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.max_redirect_times = num;
+        }
+        self
+    }
+
+    /// Sets the buffer size for the HTTP request configuration.
+    ///
+    /// This method allows you to set the size of the buffer used for reading
+    /// the HTTP response. It modifies the `buffer` field of the HTTP request's
+    /// configuration, which will be used when processing the response data.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The size of the buffer to be used, in bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - Returns a mutable reference to `self`, allowing for method chaining.
+    pub fn buffer(&mut self, buffer: usize) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.buffer = buffer;
+        }
+        self
+    }
+
+    /// Enables automatic response decoding.
+    ///
+    /// When enabled, the response body will be automatically decompressed if it is encoded
+    /// using a supported compression format (e.g., `gzip`, `deflate`, `br`).
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn decode(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.decode = true;
+        }
+        self
+    }
+
+    /// Disables automatic response decoding.
+    ///
+    /// When disabled, the response body will not be automatically decompressed,
+    /// and the raw encoded data will be returned as-is.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn undecode(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.decode = false;
+        }
+        self
+    }
+
+    /// Sets an HTTP proxy for the request.
+    ///
+    /// This method configures the request to use an HTTP proxy server.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Http,
+                host: host.to_string(),
+                port,
+                username: None,
+                password: None,
+            });
+        }
+        self
+    }
+
+    /// Sets an HTTPS proxy for the request.
+    ///
+    /// This method configures the request to use an HTTPS proxy server.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn https_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Https,
+                host: host.to_string(),
+                port,
+                username: None,
+                password: None,
+            });
+        }
+        self
+    }
+
+    /// Sets a SOCKS5 proxy for the request.
+    ///
+    /// This method configures the request to use a SOCKS5 proxy server.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn socks5_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Socks5,
+                host: host.to_string(),
+                port,
+                username: None,
+                password: None,
+            });
+        }
+        self
+    }
+
+    /// Sets an HTTP proxy with authentication for the request.
+    ///
+    /// This method configures the request to use an HTTP proxy server with username and password authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    /// - `str` - The username for proxy authentication.
+    /// - `str` - The password for proxy authentication.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn http_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Http,
+                host: host.to_string(),
+                port,
+                username: Some(username.to_string()),
+                password: Some(password.to_string()),
+            });
+        }
+        self
+    }
+
+    /// Sets an HTTPS proxy with authentication for the request.
+    ///
+    /// This method configures the request to use an HTTPS proxy server with username and password authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    /// - `str` - The username for proxy authentication.
+    /// - `str` - The password for proxy authentication.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn https_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Https,
+                host: host.to_string(),
+                port,
+                username: Some(username.to_string()),
+                password: Some(password.to_string()),
+            });
+        }
+        self
+    }
+
+    /// Sets a SOCKS5 proxy with authentication for the request.
+    ///
+    /// This method configures the request to use a SOCKS5 proxy server with username and password authentication.
+    ///
+    /// # Arguments
+    ///
+    /// - `str` - The hostname or IP address of the proxy server.
+    /// - `u16` - The port number of the proxy server.
+    /// - `str` - The username for proxy authentication.
+    /// - `str` - The password for proxy authentication.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn socks5_proxy_auth(
+        &mut self,
+        host: &str,
+        port: u16,
+        username: &str,
+        password: &str,
+    ) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.proxy = Some(ProxyConfig {
+                proxy_type: ProxyType::Socks5,
+                host: host.to_string(),
+                port,
+                username: Some(username.to_string()),
+                password: Some(password.to_string()),
+            });
+        }
+        self
+    }
+
+    /// Trusts the CA certificates of a PEM bundle, in addition to the default roots.
+    ///
+    /// The certificates are used for the target, HTTPS proxies and TLS tunnelled through
+    /// a proxy. Invalid data makes the request fail when a TLS connection is opened.
+    ///
     /// # Arguments
     ///
-    /// - `&str` - The request URL.
+    /// - `&[u8]` - The PEM data, holding one or more certificates.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    fn url(&mut self, url: &str) -> &mut Self {
-        self.http_request.url = Arc::new(url.to_owned());
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn root_cert_pem(&mut self, pem: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().add_root_cert_pem(pem);
+        }
         self
     }
 
-    /// Forces HTTP/1.1 protocol version.
+    /// Trusts a DER-encoded CA certificate, in addition to the default roots.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The DER data of the certificate.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn http1_1_only(&mut self) -> &mut Self {
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn root_cert_der(&mut self, der: &[u8]) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.http_version = HttpVersion::HTTP1_1;
+            config.tls.detach_cache().add_root_cert_der(der);
         }
         self
     }
 
-    /// Forces HTTP/2 protocol version.
+    /// Trusts the CA certificates of a PEM bundle or DER file, in addition to the default roots.
+    ///
+    /// The file is read immediately. A missing or invalid file makes the request fail when
+    /// a TLS connection is opened.
+    ///
+    /// # Arguments
+    ///
+    /// - `P` - The path of the file.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn http2_only(&mut self) -> &mut Self {
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn root_cert_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.http_version = HttpVersion::HTTP2;
+            config.tls.detach_cache().add_root_cert_file(path.as_ref());
         }
         self
     }
 
-    /// Sets request headers.
-    ///
-    /// # Arguments
+    /// Trusts the certificates of the operating system trust store, in addition to the default roots.
     ///
-    /// - `HashMapXxHash3_64<K, V>` - The headers to set.
+    /// The store is read immediately; `SSL_CERT_FILE` and `SSL_CERT_DIR` take precedence over
+    /// the platform store when set. Combine with `without_webpki_roots` to trust the system
+    /// store only. Certificates that cannot be read or parsed make the request fail when
+    /// a TLS connection is opened, with an error naming them.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn headers<K, V>(&mut self, header: HashMapXxHash3_64<K, V>) -> &mut Self
-    where
-        K: ToString,
-        V: ToString,
-    {
-        if let Some(tmp_header) = Arc::get_mut(&mut self.http_request.header) {
-            for (key, value) in header {
-                let key_str: String = key.to_string();
-                let value_str: String = value.to_string();
-                let mut found_existing: bool = false;
-                let mut existing_key: Option<String> = None;
-                for existing_key_ref in tmp_header.keys() {
-                    if existing_key_ref.eq_ignore_ascii_case(&key_str) {
-                        existing_key = Some(existing_key_ref.clone());
-                        found_existing = true;
-                        break;
-                    }
-                }
-                if found_existing {
-                    if let Some(existing_key) = existing_key {
-                        tmp_header.remove(&existing_key);
-                    }
-                }
-                let mut value_deque: VecDeque<String> = VecDeque::new();
-                value_deque.push_front(value_str);
-                tmp_header.insert(key_str, value_deque);
-            }
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn system_root_certs(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().add_system_root_certs();
         }
         self
     }
 
-    /// Sets JSON request body.
-    ///
-    /// # Arguments
-    ///
-    /// - `JsonValue` - The JSON body data.
+    /// Stops trusting the bundled webpki roots, so only the added root certificates are used.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn json(&mut self, body: JsonValue) -> &mut Self {
-        if let JsonValue::Object(map) = body {
-            let mut res_body: HashMapXxHash3_64<String, JsonValue> = hash_map_xx_hash3_64();
-            for (k, v) in map.iter() {
-                res_body.insert(k.to_string(), v.clone());
-            }
-            self.http_request.body = Arc::new(Body::Json(res_body));
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn without_webpki_roots(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().webpki_roots = false;
         }
         self
     }
 
-    /// Sets plain text request body.
+    /// Presents a client certificate for mutual TLS, from PEM data.
+    ///
+    /// The certificate is sent to the target and to HTTPS proxies that request one. Invalid
+    /// data makes the request fail when a TLS connection is opened.
     ///
     /// # Arguments
     ///
-    /// - `T` - The text body data (must implement ToString).
+    /// - `&[u8]` - The PEM certificate chain, leaf first.
+    /// - `&[u8]` - The PEM private key (PKCS#8, PKCS#1 or SEC1).
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn text<T: ToString>(&mut self, body: T) -> &mut Self {
-        self.http_request.body = Arc::new(Body::Text(body.to_string()));
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn client_cert_pem(&mut self, cert_chain: &[u8], key: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config
+                .tls
+                .detach_cache()
+                .set_client_cert_pem(cert_chain, key);
+        }
         self
     }
 
-    /// Sets binary request body.
+    /// Presents a client certificate for mutual TLS, from DER data.
     ///
     /// # Arguments
     ///
-    /// - `T` - The binary body data (must implement Into<Vec<u8>>).
+    /// - `&[u8]` - The DER certificate.
+    /// - `&[u8]` - The DER private key (PKCS#8, PKCS#1 or SEC1).
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn body<T: Into<Vec<u8>>>(&mut self, body: T) -> &mut Self {
-        self.http_request.body = Arc::new(Body::Binary(body.into()));
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn client_cert_der(&mut self, cert: &[u8], key: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().set_client_cert_der(cert, key);
+        }
         self
     }
 
-    /// Sets the timeout value for the current connection.
+    /// Presents a client certificate for mutual TLS, from PEM or DER files.
     ///
-    /// This method sets the timeout duration for the connection, which is used to determine
-    /// how long the system should wait for a response before considering the connection attempt
-    /// as failed. The timeout value is stored in an `Arc` to allow it to be shared safely across
-    /// multiple threads if needed.
+    /// The files are read immediately.
     ///
     /// # Arguments
     ///
-    /// - `u64` - The timeout duration in seconds. This value will be used to configure the
-    ///   connection timeout.
+    /// - `P` - The path of the certificate chain.
+    /// - `P` - The path of the private key.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn timeout(&mut self, timeout: u64) -> &mut Self {
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn client_cert_file<P: AsRef<Path>>(&mut self, cert_path: P, key_path: P) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.timeout = timeout;
+            config
+                .tls
+                .detach_cache()
+                .set_client_cert_file(cert_path.as_ref(), key_path.as_ref());
         }
         self
     }
 
-    /// Enables HTTP redirection for the request.
+    /// Accepts any server certificate, including expired, self-signed or mismatched ones.
     ///
-    /// This method sets the `redirect` property of the `http_request` to `true`.
+    /// This disables the protection TLS offers against impersonation and is meant for throwaway
+    /// test environments only. Pins still apply, and HTTPS proxies are still verified against
+    /// the trusted roots.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn redirect(&mut self) -> &mut Self {
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn danger_accept_invalid_certs(&mut self) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.redirect = true;
+            config.tls.detach_cache().verification = CertVerification::AcceptInvalid;
         }
         self
     }
 
-    /// Unenables HTTP redirection for the request.
+    /// Verifies the certificates of the target with a custom policy instead of the trusted roots.
     ///
-    /// This method sets the `redirect` property of the `http_request` to `false`.
+    /// HTTPS proxies are still verified against the trusted roots.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn ServerCertVerifier>` - The verifier, implementing
+    ///   `rustls::client::danger::ServerCertVerifier`.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - The builder for method chaining.
-    pub fn unredirect(&mut self) -> &mut Self {
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn cert_verifier(&mut self, verifier: Arc<dyn ServerCertVerifier>) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.redirect = false;
-        };
+            config.tls.detach_cache().verification = CertVerification::Custom(verifier);
+        }
         self
     }
 
-    /// Sets the maximum number of allowed redirections for the HTTP request.
+    /// Pins the SHA-256 hash of a subject public key info (SPKI).
     ///
-    /// This method updates the `max_redirect_times` field in the configuration and returns a mutable
-    /// reference to `self` to enable method chaining.
+    /// Once a pin is added, the leaf certificate of the target must have a pinned public key or
+    /// be a pinned certificate after the chain passed verification, otherwise the connection
+    /// fails with a `CertificatePinError`. May be called several times to allow backup keys.
     ///
     /// # Arguments
     ///
-    /// - `usize` - The maximum number of redirections allowed. A value of `0` disables redirection.
+    /// - `&str` - The base64 hash, optionally prefixed with `sha256//` as curl accepts it.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - A mutable reference to the current instance for method chaining.
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn pin_spki_sha256(&mut self, pin: &str) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().add_spki_pin(pin);
+        }
+        self
+    }
+
+    /// Pins the SHA-256 hash of the DER encoded leaf certificate of the server.
     ///
-    /// # Notes
+    /// # Arguments
     ///
-    /// Ensure that the value provided to `num` is within a valid range. Excessively high values
-    /// may lead to performance issues or unintended behavior.
-    pub fn max_redirect_times(&mut self, num: usize) -> &mut Self {
+    /// - `&str` - The base64 hash, optionally prefixed with `sha256//`.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn pin_cert_sha256(&mut self, pin: &str) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.max_redirect_times = num;
+            config.tls.detach_cache().add_cert_pin(pin);
         }
         self
     }
 
-    /// Sets the buffer size for the HTTP request configuration.
-    ///
-    /// This method allows you to set the size of the buffer used for reading
-    /// the HTTP response. It modifies the `buffer` field of the HTTP request's
-    /// configuration, which will be used when processing the response data.
+    /// Restricts the TLS protocol versions that may be negotiated.
     ///
     /// # Arguments
     ///
-    /// - `usize` - The size of the buffer to be used, in bytes.
+    /// - `&[&'static SupportedProtocolVersion]` - The allowed versions, such as
+    ///   `rustls::version::TLS13`.
     ///
     /// # Returns
     ///
-    /// - `&mut RequestBuilder` - Returns a mutable reference to `self`, allowing for method chaining.
-    pub fn buffer(&mut self, buffer: usize) -> &mut Self {
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn tls_versions(&mut self, versions: &[&'static SupportedProtocolVersion]) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.buffer = buffer;
+            config.tls.detach_cache().versions = versions.to_vec();
         }
         self
     }
 
-    /// Enables automatic response decoding.
-    ///
-    /// When enabled, the response body will be automatically decompressed if it is encoded
-    /// using a supported compression format (e.g., `gzip`, `deflate`, `br`).
+    /// Only negotiates TLS 1.3.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn decode(&mut self) -> &mut Self {
+    pub fn tls1_3_only(&mut self) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.decode = true;
+            config.tls.detach_cache().versions = vec![&TLS13];
         }
         self
     }
 
-    /// Disables automatic response decoding.
+    /// Restricts the cipher suites offered to the server.
     ///
-    /// When disabled, the response body will not be automatically decompressed,
-    /// and the raw encoded data will be returned as-is.
+    /// # Arguments
+    ///
+    /// - `&[SupportedCipherSuite]` - The allowed suites in order of preference, such as
+    ///   `rustls::crypto::aws_lc_rs::cipher_suite::TLS13_AES_256_GCM_SHA384`.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn undecode(&mut self) -> &mut Self {
+    pub fn tls_cipher_suites(&mut self, cipher_suites: &[SupportedCipherSuite]) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.decode = false;
+            config.tls.detach_cache().cipher_suites = cipher_suites.to_vec();
         }
         self
     }
 
-    /// Sets an HTTP proxy for the request.
+    /// Stops sending the server name in the SNI extension.
     ///
-    /// This method configures the request to use an HTTP proxy server.
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn without_tls_sni(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().sni = false;
+        }
+        self
+    }
+
+    /// Overrides the name sent in SNI and verified against the certificate of the target.
+    ///
+    /// Useful when connecting by IP address or through an alias of the host. HTTPS proxies
+    /// keep their own name.
     ///
     /// # Arguments
     ///
-    /// - `str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
+    /// - `&str` - The DNS name or IP address.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn http_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+    pub fn tls_server_name(&mut self, name: &str) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Http,
-                host: host.to_string(),
-                port,
-                username: None,
-                password: None,
-            });
+            config.tls.detach_cache().set_server_name(name);
         }
         self
     }
 
-    /// Sets an HTTPS proxy for the request.
+    /// Disables TLS session resumption, so every connection performs a full handshake.
     ///
-    /// This method configures the request to use an HTTPS proxy server.
+    /// # Returns
+    ///
+    /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
+    pub fn without_tls_session_resumption(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.http_request.config.write() {
+            config.tls.detach_cache().session_resumption = false;
+        }
+        self
+    }
+
+    /// Appends the TLS secrets of this request to a key log file, so captures can be decoded.
+    ///
+    /// Without this, secrets are logged to the file named by `SSLKEYLOGFILE` when it is set.
     ///
     /// # Arguments
     ///
-    /// - `str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
+    /// - `P` - The path of the key log file, created if needed.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn https_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+    pub fn tls_key_log_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Https,
-                host: host.to_string(),
-                port,
-                username: None,
-                password: None,
-            });
+            config.tls.detach_cache().set_key_log_file(path.as_ref());
         }
         self
     }
 
-    /// Sets a SOCKS5 proxy for the request.
-    ///
-    /// This method configures the request to use a SOCKS5 proxy server.
+    /// Hands the TLS secrets of this request to a custom key logger.
     ///
     /// # Arguments
     ///
-    /// - `str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
+    /// - `Arc<dyn KeyLog>` - The key logger.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn socks5_proxy(&mut self, host: &str, port: u16) -> &mut Self {
+    pub fn tls_key_log(&mut self, key_log: Arc<dyn KeyLog>) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Socks5,
-                host: host.to_string(),
-                port,
-                username: None,
-                password: None,
-            });
+            config.tls.detach_cache().key_log = Some(TlsKeyLog(key_log));
         }
         self
     }
 
-    /// Sets an HTTP proxy with authentication for the request.
+    /// Connects to an address instead of resolving a host and port, like curl's `--resolve`.
     ///
-    /// This method configures the request to use an HTTP proxy server with username and password authentication.
+    /// The `Host` header and TLS server name still use the host. Calling it again for the same
+    /// host and port adds another address to try.
     ///
     /// # Arguments
     ///
-    /// - `str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
-    /// - `str` - The username for proxy authentication.
-    /// - `str` - The password for proxy authentication.
+    /// - `&str` - The host name, matched case-insensitively.
+    /// - `u16` - The port.
+    /// - `IpAddr` - The address to connect to.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn http_proxy_auth(
-        &mut self,
-        host: &str,
-        port: u16,
-        username: &str,
-        password: &str,
-    ) -> &mut Self {
+    pub fn resolve(&mut self, host: &str, port: u16, addr: IpAddr) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Http,
-                host: host.to_string(),
-                port,
-                username: Some(username.to_string()),
-                password: Some(password.to_string()),
-            });
+            StaticResolver::insert_entry(&mut config.dns.detach().overrides, host, port, addr);
         }
         self
     }
 
-    /// Sets an HTTPS proxy with authentication for the request.
-    ///
-    /// This method configures the request to use an HTTPS proxy server with username and password authentication.
+    /// Resolves hosts without an address set by `resolve` with a custom resolver, replacing the
+    /// system resolver and any DNS cache.
     ///
     /// # Arguments
     ///
-    /// - `str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
-    /// - `str` - The username for proxy authentication.
-    /// - `str` - The password for proxy authentication.
+    /// - `Arc<dyn Resolver>` - The resolver.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn https_proxy_auth(
-        &mut self,
-        host: &str,
-        port: u16,
-        username: &str,
-        password: &str,
-    ) -> &mut Self {
+    pub fn resolver(&mut self, resolver: Arc<dyn Resolver>) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Https,
-                host: host.to_string(),
-                port,
-                username: Some(username.to_string()),
-                password: Some(password.to_string()),
-            });
+            config.dns.detach().resolver = SharedResolver(resolver);
         }
         self
     }
 
-    /// Sets a SOCKS5 proxy with authentication for the request.
-    ///
-    /// This method configures the request to use a SOCKS5 proxy server with username and password authentication.
+    /// Reuses the answers of the resolver set so far for a fixed time.
     ///
     /// # Arguments
     ///
-    /// - `str` - The hostname or IP address of the proxy server.
-    /// - `u16` - The port number of the proxy server.
-    /// - `str` - The username for proxy authentication.
-    /// - `str` - The password for proxy authentication.
+    /// - `u64` - How long an answer is reused, in milliseconds.
     ///
     /// # Returns
     ///
     /// - `&mut RequestBuilder` - A mutable reference to the current instance, allowing for method chaining.
-    pub fn socks5_proxy_auth(
-        &mut self,
-        host: &str,
-        port: u16,
-        username: &str,
-        password: &str,
-    ) -> &mut Self {
+    pub fn dns_cache(&mut self, ttl: u64) -> &mut Self {
         if let Ok(mut config) = self.http_request.config.write() {
-            config.proxy = Some(ProxyConfig {
-                proxy_type: ProxyType::Socks5,
-                host: host.to_string(),
-                port,
-                username: Some(username.to_string()),
-                password: Some(password.to_string()),
-            });
+            config
+                .dns
+                .detach()
+                .set_cache_ttl(Duration::from_millis(ttl));
         }
         self
     }
@@ -483,6 +1252,7 @@ impl RequestBuilder {
     ///
     /// - `BoxAsyncRequestTrait` - Returns a fully constructed `BoxAsyncRequestTrait` instance based on the current builder state.
     pub fn build_async(&mut self) -> BoxAsyncRequestTrait {
+        self.apply_query();
         self.builder = self.http_request.clone();
         self.http_request = HttpRequest::default();
         Box::new(self.builder.clone())
@@ -498,6 +1268,7 @@ impl RequestBuilder {
     ///
     /// - `BoxRequestTrait` - Returns a fully constructed `BoxRequestTrait` instance based on the current builder state.
     pub fn build_sync(&mut self) -> BoxRequestTrait {
+        self.apply_query();
         self.builder = self.http_request.clone();
         self.http_request = HttpRequest::default();
         Box::new(self.builder.clone())
@@ -11,6 +11,10 @@ use crate::*;
 ///   the construction process. It holds the current state of the builder.
 /// - `builder`: A finalized `HttpRequest` instance that holds the result after the
 ///   builder process has been completed. It is returned when the builder is finalized.
+/// - `query`: Encoded query parameters added with `query`, appended to the URL when the
+///   builder is finalized so they apply whatever order the URL and parameters are set in.
+/// - `base_url`: Base URL of the client the builder was created from, which relative URLs
+///   passed to the URL setters are resolved against.
 ///
 /// This builder simplifies the creation of `HttpRequest` objects, ensuring thread-safety
 /// and immutability of shared references, while providing a fluent API for constructing
@@ -19,4 +23,6 @@ use crate::*;
 pub struct RequestBuilder {
     pub(crate) http_request: HttpRequest,
     pub(crate) builder: HttpRequest,
+    pub(crate) query: String,
+    pub(crate) base_url: Option<String>,
 }
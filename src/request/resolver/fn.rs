@@ -0,0 +1,15 @@
+use crate::*;
+
+/// Builds the key a host and port are looked up under, ignoring the case of the host.
+///
+/// # Arguments
+///
+/// - `&str` - The host name.
+/// - `u16` - The port.
+///
+/// # Returns
+///
+/// - `ResolveKey` - The lowercase host and the port.
+pub(crate) fn get_resolve_key(host: &str, port: u16) -> ResolveKey {
+    (host.to_ascii_lowercase(), port)
+}
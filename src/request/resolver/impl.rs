@@ -0,0 +1,137 @@
+use crate::*;
+
+impl Resolver for SystemResolver {
+    fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        Ok((host, port).to_socket_addrs()?.collect())
+    }
+}
+
+impl Default for StaticResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StaticResolver {
+    /// Creates a resolver without fixed addresses that falls back to the system resolver.
+    ///
+    /// # Returns
+    ///
+    /// - `StaticResolver` - The resolver.
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::new(),
+            fallback: Arc::new(SystemResolver),
+        }
+    }
+
+    /// Sets the resolver used for hosts without fixed addresses.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn Resolver>` - The fallback resolver.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut StaticResolver` - The resolver for method chaining.
+    pub fn fallback(&mut self, fallback: Arc<dyn Resolver>) -> &mut Self {
+        self.fallback = fallback;
+        self
+    }
+
+    /// Answers a host and port with an address, added after any earlier ones for the same pair.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host name, matched case-insensitively.
+    /// - `u16` - The port.
+    /// - `IpAddr` - The address connections are opened to.
+    ///
+    /// # Returns
+    ///
+    /// - `&mut StaticResolver` - The resolver for method chaining.
+    pub fn insert(&mut self, host: &str, port: u16, addr: IpAddr) -> &mut Self {
+        Self::insert_entry(&mut self.entries, host, port, addr);
+        self
+    }
+
+    /// Adds an address for a host and port to a map of fixed addresses.
+    ///
+    /// # Arguments
+    ///
+    /// - `&mut HashMap<ResolveKey, Vec<SocketAddr>>` - The map.
+    /// - `&str` - The host name.
+    /// - `u16` - The port.
+    /// - `IpAddr` - The address.
+    pub(crate) fn insert_entry(
+        entries: &mut HashMap<ResolveKey, Vec<SocketAddr>>,
+        host: &str,
+        port: u16,
+        addr: IpAddr,
+    ) {
+        entries
+            .entry(get_resolve_key(host, port))
+            .or_default()
+            .push(SocketAddr::new(addr, port));
+    }
+}
+
+impl Resolver for StaticResolver {
+    fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        match self.entries.get(&get_resolve_key(host, port)) {
+            Some(addrs) => Ok(addrs.clone()),
+            None => self.fallback.resolve(host, port),
+        }
+    }
+}
+
+impl CachingResolver {
+    /// Creates a cache in front of another resolver.
+    ///
+    /// # Arguments
+    ///
+    /// - `Arc<dyn Resolver>` - The resolver performing the lookups.
+    /// - `Duration` - How long an answer is reused.
+    ///
+    /// # Returns
+    ///
+    /// - `CachingResolver` - The resolver with an empty cache.
+    pub fn new(inner: Arc<dyn Resolver>, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            entries: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Forgets every cached answer.
+    pub fn clear(&self) {
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.clear();
+        }
+    }
+}
+
+impl Resolver for CachingResolver {
+    fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>> {
+        let key: ResolveKey = get_resolve_key(host, port);
+        let now: Instant = Instant::now();
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.retain(|_, entry| entry.expires > now);
+            if let Some(entry) = entries.get(&key) {
+                return Ok(entry.addrs.clone());
+            }
+        }
+        let addrs: Vec<SocketAddr> = self.inner.resolve(host, port)?;
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(
+                key,
+                DnsCacheEntry {
+                    addrs: addrs.clone(),
+                    expires: now + self.ttl,
+                },
+            );
+        }
+        Ok(addrs)
+    }
+}
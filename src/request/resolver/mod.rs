@@ -0,0 +1,11 @@
+pub(crate) mod r#fn;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+pub(crate) mod r#trait;
+pub(crate) mod r#type;
+
+pub use r#struct::*;
+pub use r#trait::*;
+
+pub(crate) use r#fn::*;
+pub(crate) use r#type::*;
@@ -0,0 +1,37 @@
+use crate::*;
+
+/// Resolver asking the operating system, used unless another resolver is set.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemResolver;
+
+/// Resolver answering configured host and port pairs with fixed addresses, like curl's
+/// `--resolve`, and passing every other lookup to a fallback resolver.
+#[derive(Debug, Clone)]
+pub struct StaticResolver {
+    /// Fixed addresses by lowercase host and port.
+    pub(crate) entries: HashMap<ResolveKey, Vec<SocketAddr>>,
+    /// Resolver for hosts without fixed addresses.
+    pub(crate) fallback: Arc<dyn Resolver>,
+}
+
+/// Resolver remembering the answers of another resolver for a fixed time.
+///
+/// Clones share the cached answers.
+#[derive(Debug, Clone)]
+pub struct CachingResolver {
+    /// Resolver performing the lookups.
+    pub(crate) inner: Arc<dyn Resolver>,
+    /// How long an answer is reused.
+    pub(crate) ttl: Duration,
+    /// Cached answers by lowercase host and port.
+    pub(crate) entries: Arc<Mutex<HashMap<ResolveKey, DnsCacheEntry>>>,
+}
+
+/// Answer held by a `CachingResolver`.
+#[derive(Debug, Clone)]
+pub(crate) struct DnsCacheEntry {
+    /// The resolved addresses.
+    pub(crate) addrs: Vec<SocketAddr>,
+    /// When the answer stops being reused.
+    pub(crate) expires: Instant,
+}
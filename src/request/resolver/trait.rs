@@ -0,0 +1,19 @@
+use crate::*;
+
+/// Resolves host names to the socket addresses HTTP and WebSocket connections are opened to.
+///
+/// Lookups run on a blocking thread, so implementations may block. The resolved addresses only
+/// change where the connection goes; the `Host` header and TLS server name keep the URL host.
+pub trait Resolver: Debug + Send + Sync {
+    /// Resolves a host and port.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The host name.
+    /// - `u16` - The port.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Result<Vec<SocketAddr>>` - The addresses, tried in order.
+    fn resolve(&self, host: &str, port: u16) -> io::Result<Vec<SocketAddr>>;
+}
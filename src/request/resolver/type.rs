@@ -0,0 +1,2 @@
+/// Host and port pair identifying a lookup.
+pub(crate) type ResolveKey = (String, u16);
@@ -0,0 +1,27 @@
+/// Parsing states of a chunked transfer-encoded response body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ChunkedState {
+    /// Waiting for a chunk-size line, including any chunk extensions.
+    Size,
+    /// Reading chunk data with the given number of bytes remaining.
+    Data(usize),
+    /// Waiting for the CRLF that terminates chunk data.
+    DataEnd,
+    /// Reading trailer fields after the last chunk.
+    Trailer,
+    /// The whole chunked body has been received.
+    Done,
+}
+
+/// How the end of a response body is determined, following RFC 9112 section 6.3.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ResponseFraming {
+    /// The response never has a body (HEAD, 1xx, 204 and 304).
+    NoBody,
+    /// The body length is given by `Content-Length`.
+    ContentLength(usize),
+    /// The body uses chunked transfer coding.
+    Chunked,
+    /// The body ends when the server closes the connection.
+    UntilClose,
+}
@@ -39,62 +39,63 @@ impl SharedRequestBuilder {
         request
     }
 
-    /// Constructs an HTTP GET request byte vector.
+    /// Checks whether a request with the given method may carry a body.
+    ///
+    /// `GET`, `HEAD`, `TRACE` and `CONNECT` requests are always sent without content.
     ///
     /// # Arguments
     ///
-    /// - `String` - The request path.
-    /// - `Vec<u8>` - The raw bytes of the request headers.
-    /// - `String` - The HTTP version string (e.g., "HTTP/1.1").
+    /// - `&Method` - The HTTP method.
     ///
     /// # Returns
     ///
-    /// - `Vec<u8>` - The complete HTTP GET request as a byte vector.
-    pub(crate) fn build_get_request(
-        path: String,
-        header_bytes: Vec<u8>,
-        http_version_str: String,
-    ) -> Vec<u8> {
-        Self::build_http_request("GET", path, header_bytes, None, http_version_str)
+    /// - `bool` - Whether the body should be written for this method.
+    pub(crate) fn method_allows_body(method: &Method) -> bool {
+        !(method.is_get() || method.is_head() || method.is_trace() || method.is_connect())
     }
 
-    /// Constructs an HTTP POST request byte vector.
+    /// Checks whether the semantics of the given method anticipate a request body.
+    ///
+    /// For these methods `Content-Length` is always sent, even when the body is empty.
     ///
     /// # Arguments
     ///
-    /// - `String` - The request path.
-    /// - `Vec<u8>` - The raw bytes of the request headers.
-    /// - `Vec<u8>` - The raw bytes of the request body.
-    /// - `String` - The HTTP version string (e.g., "HTTP/1.1").
+    /// - `&Method` - The HTTP method.
     ///
     /// # Returns
     ///
-    /// - `Vec<u8>` - The complete HTTP POST request as a byte vector.
-    pub(crate) fn build_post_request(
-        path: String,
-        header_bytes: Vec<u8>,
-        body_bytes: Vec<u8>,
-        http_version_str: String,
-    ) -> Vec<u8> {
-        Self::build_http_request(
-            "POST",
-            path,
-            header_bytes,
-            Some(body_bytes),
-            http_version_str,
-        )
+    /// - `bool` - Whether the method expects a body.
+    pub(crate) fn method_expects_body(method: &Method) -> bool {
+        method.is_post() || method.is_put() || method.is_patch()
+    }
+
+    /// Checks whether the given method can be written into a request line.
+    ///
+    /// A method must be a non-empty RFC 9110 token, which rules out whitespace,
+    /// control characters and separators.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The method name.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the method is a valid token.
+    pub(crate) fn is_valid_method(method: &str) -> bool {
+        !method.is_empty()
+            && method
+                .bytes()
+                .all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
     }
 }
 
 impl SharedResponseHandler {
-    /// Parses response headers to extract status code, content length, and redirect URL.
+    /// Parses response headers to extract the redirect URL.
     ///
     /// # Arguments
     ///
     /// - `&[u8]` - The raw bytes of the response headers.
-    /// - `&[u8]` - The raw bytes of the HTTP version (e.g., "HTTP/1.1").
     /// - `&[u8]` - The byte pattern to identify the "Location" header.
-    /// - `&mut usize` - A mutable reference to store the content length.
     /// - `&mut Option<Vec<u8>>` - A mutable reference to store the redirect URL if present.
     ///
     /// # Returns
@@ -102,36 +103,168 @@ impl SharedResponseHandler {
     /// - `Result<(), RequestError>` - Ok if parsing is successful, Err otherwise.
     pub(crate) fn parse_response_headers(
         headers_bytes: &[u8],
-        http_version_bytes: &[u8],
         location_sign_key: &[u8],
-        content_length: &mut usize,
         redirect_url: &mut Option<Vec<u8>>,
     ) -> Result<(), RequestError> {
-        if let Some(status_pos) =
-            Self::find_pattern_case_insensitive(headers_bytes, http_version_bytes)
+        let status_code: usize = Self::get_status_code(headers_bytes);
+        if (300..=399).contains(&status_code)
+            && let Some(location_pos) =
+                Self::find_pattern_case_insensitive(headers_bytes, location_sign_key)
+        {
+            let start: usize = location_pos + location_sign_key.len();
+            if let Some(end_pos) = Self::find_crlf(headers_bytes, start) {
+                let mut url_vec: Vec<u8> = Vec::with_capacity(end_pos - start);
+                url_vec.extend_from_slice(headers_bytes[start..end_pos].trim_ascii());
+                *redirect_url = Some(url_vec);
+            }
+        }
+        Ok(())
+    }
+
+    /// Extracts the status code from the status line of a response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `usize` - The status code, or 0 if the status line is malformed.
+    pub(crate) fn get_status_code(headers_bytes: &[u8]) -> usize {
+        let status_line_end: usize =
+            Self::find_crlf(headers_bytes, 0).unwrap_or(headers_bytes.len());
+        split_whitespace(&headers_bytes[..status_line_end])
+            .get(1)
+            .map_or(0, |status_bytes| Self::parse_status_code(status_bytes))
+    }
+
+    /// Checks whether a status code belongs to an interim response that precedes the final one.
+    ///
+    /// `101 Switching Protocols` is final, every other 1xx response is interim.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The status code.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the response is interim.
+    pub(crate) fn is_interim_status(status_code: usize) -> bool {
+        (100..=199).contains(&status_code) && status_code != 101
+    }
+
+    /// Determines how the body of a response is delimited.
+    ///
+    /// Implements the message body length rules of RFC 9112 section 6.3.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `usize` - The response status code.
+    /// - `&Method` - The method of the request.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseFraming` - The body framing.
+    pub(crate) fn get_response_framing(
+        headers_bytes: &[u8],
+        status_code: usize,
+        method: &Method,
+    ) -> ResponseFraming {
+        if method.is_head()
+            || (100..=199).contains(&status_code)
+            || status_code == 204
+            || status_code == 304
+        {
+            return ResponseFraming::NoBody;
+        }
+        if Self::is_chunked(headers_bytes) {
+            return ResponseFraming::Chunked;
+        }
+        if Self::has_header(headers_bytes, TRANSFER_ENCODING) {
+            return ResponseFraming::UntilClose;
+        }
+        match Self::get_content_length(headers_bytes) {
+            Some(content_length) => ResponseFraming::ContentLength(content_length),
+            None => ResponseFraming::UntilClose,
+        }
+    }
+
+    /// Checks whether the connection may stay open after a response.
+    ///
+    /// HTTP/1.1 connections are persistent unless `Connection: close` is sent, HTTP/1.0
+    /// connections only when `Connection: keep-alive` is sent. Protocol switches never are.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `usize` - The response status code.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the connection can be kept alive.
+    pub(crate) fn is_keep_alive(headers_bytes: &[u8], status_code: usize) -> bool {
+        if status_code == 101 {
+            return false;
+        }
+        let mut keep_alive: bool = headers_bytes.starts_with(HTTP_VERSION_1_1.as_bytes());
+        for line in split_multi_byte(headers_bytes, HTTP_BR_BYTES)
+            .into_iter()
+            .skip(1)
         {
-            let status_code_start: usize = status_pos + http_version_bytes.len() + 1;
-            let status_code_end: usize = status_code_start + 3;
-            if status_code_end <= headers_bytes.len() {
-                let status_code: usize =
-                    Self::parse_status_code(&headers_bytes[status_code_start..status_code_end]);
-
-                if (300..=399).contains(&status_code) {
-                    if let Some(location_pos) =
-                        Self::find_pattern_case_insensitive(headers_bytes, location_sign_key)
-                    {
-                        let start: usize = location_pos + location_sign_key.len();
-                        if let Some(end_pos) = Self::find_crlf(headers_bytes, start) {
-                            let mut url_vec = Vec::with_capacity(end_pos - start);
-                            url_vec.extend_from_slice(&headers_bytes[start..end_pos]);
-                            *redirect_url = Some(url_vec);
-                        }
+            if let Some((key, value)) = Self::split_header_line(line)
+                && key.eq_ignore_ascii_case(CONNECTION.as_bytes())
+            {
+                for token in value.split(|&byte| byte == b',') {
+                    let token: &[u8] = token.trim_ascii();
+                    if token.eq_ignore_ascii_case(CLOSE.as_bytes()) {
+                        return false;
+                    }
+                    if token.eq_ignore_ascii_case(KEEP_ALIVE.as_bytes()) {
+                        keep_alive = true;
                     }
                 }
             }
         }
-        *content_length = Self::get_content_length(headers_bytes);
-        Ok(())
+        keep_alive
+    }
+
+    /// Checks whether an I/O error means the peer has closed the connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `ErrorKind` - The kind of the I/O error.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the connection was closed.
+    pub(crate) fn is_connection_closed(kind: ErrorKind) -> bool {
+        matches!(
+            kind,
+            ErrorKind::ConnectionReset
+                | ErrorKind::ConnectionAborted
+                | ErrorKind::BrokenPipe
+                | ErrorKind::NotConnected
+                | ErrorKind::UnexpectedEof
+        )
+    }
+
+    /// Checks whether a header is present in the response headers.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the header is present.
+    pub(crate) fn has_header(headers_bytes: &[u8], name: &str) -> bool {
+        split_multi_byte(headers_bytes, HTTP_BR_BYTES)
+            .into_iter()
+            .skip(1)
+            .filter_map(Self::split_header_line)
+            .any(|(key, _)| key.eq_ignore_ascii_case(name.as_bytes()))
     }
 
     /// Finds a pattern within a byte slice, ignoring case.
@@ -216,31 +349,23 @@ impl SharedResponseHandler {
 
     /// Extracts the Content-Length value from response bytes.
     ///
-    /// Searches for the "Content-Length" header and parses its value.
+    /// Searches the header lines for "Content-Length" and parses its value.
     ///
     /// # Arguments
     ///
-    /// - `&[u8]` - The raw bytes of the HTTP response.
+    /// - `&[u8]` - The raw bytes of the HTTP response headers.
     ///
     /// # Returns
     ///
-    /// - `usize` - The content length value, or 0 if not found or parsing fails.
-    pub(crate) fn get_content_length(response_bytes: &[u8]) -> usize {
-        if let Some(pos) =
-            Self::find_pattern_case_insensitive(response_bytes, CONTENT_LENGTH_PATTERN)
-        {
-            let value_start: usize = pos + CONTENT_LENGTH_PATTERN.len();
-            let value_start: usize = if response_bytes.get(value_start) == Some(&b' ') {
-                value_start + 1
-            } else {
-                value_start
-            };
-            if let Some(end_pos) = Self::find_crlf(response_bytes, value_start) {
-                let value_bytes: &[u8] = &response_bytes[value_start..end_pos];
-                return Self::parse_decimal_bytes(value_bytes);
-            }
-        }
-        0
+    /// - `Option<usize>` - The content length value, or None if the header is absent.
+    pub(crate) fn get_content_length(response_bytes: &[u8]) -> Option<usize> {
+        split_multi_byte(response_bytes, HTTP_BR_BYTES)
+            .into_iter()
+            .skip(1)
+            .take_while(|line| !line.is_empty())
+            .filter_map(Self::split_header_line)
+            .find(|(key, _)| key.eq_ignore_ascii_case(CONTENT_LENGTH.as_bytes()))
+            .map(|(_, value)| Self::parse_decimal_bytes(value))
     }
 
     /// Parses a byte slice representing a decimal number into a `usize`.
@@ -296,6 +421,107 @@ impl SharedResponseHandler {
         result
     }
 
+    /// Checks whether the response body uses chunked transfer coding.
+    ///
+    /// Only the final transfer coding is considered, as required by RFC 9112.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the body is chunked.
+    pub(crate) fn is_chunked(headers_bytes: &[u8]) -> bool {
+        let mut chunked: bool = false;
+        for line in split_multi_byte(headers_bytes, HTTP_BR_BYTES)
+            .into_iter()
+            .skip(1)
+        {
+            if let Some((key, value)) = Self::split_header_line(line)
+                && key.eq_ignore_ascii_case(TRANSFER_ENCODING.as_bytes())
+            {
+                chunked = value
+                    .rsplit(|&byte| byte == b',')
+                    .next()
+                    .map(|coding| coding.trim_ascii().eq_ignore_ascii_case(CHUNKED.as_bytes()))
+                    .unwrap_or(false);
+            }
+        }
+        chunked
+    }
+
+    /// Splits a raw header line into its name and trimmed value.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw header line without the trailing CRLF.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<(&[u8], &[u8])>` - The header name and value, or None if the line has no colon.
+    pub(crate) fn split_header_line(line: &[u8]) -> Option<(&[u8], &[u8])> {
+        let colon_pos: usize = line.iter().position(|&byte| byte == b':')?;
+        Some((
+            line[..colon_pos].trim_ascii(),
+            line[colon_pos + 1..].trim_ascii(),
+        ))
+    }
+
+    /// Rebuilds a complete response from its header section and a decoded chunked body.
+    ///
+    /// The `chunked` coding is removed from `Transfer-Encoding` and trailer fields are
+    /// appended to the header section, so the result can be parsed like any other response.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The raw bytes of the response headers, including the final empty line.
+    /// - `ChunkedDecoder` - The finished decoder holding the body and trailers.
+    ///
+    /// # Returns
+    ///
+    /// - `Vec<u8>` - The reassembled response bytes.
+    pub(crate) fn assemble_chunked_response(
+        headers_bytes: &[u8],
+        decoder: ChunkedDecoder,
+    ) -> Vec<u8> {
+        let mut response: Vec<u8> =
+            Vec::with_capacity(headers_bytes.len() + decoder.trailers.len() + decoder.body.len());
+        for (index, line) in split_multi_byte(headers_bytes, HTTP_BR_BYTES)
+            .into_iter()
+            .enumerate()
+        {
+            if line.is_empty() {
+                continue;
+            }
+            if index > 0
+                && let Some((key, value)) = Self::split_header_line(line)
+                && key.eq_ignore_ascii_case(TRANSFER_ENCODING.as_bytes())
+            {
+                let codings: Vec<&[u8]> = value
+                    .split(|&byte| byte == b',')
+                    .map(|coding| coding.trim_ascii())
+                    .filter(|coding| {
+                        !coding.is_empty() && !coding.eq_ignore_ascii_case(CHUNKED.as_bytes())
+                    })
+                    .collect();
+                if !codings.is_empty() {
+                    response.extend_from_slice(key);
+                    response.extend_from_slice(b": ");
+                    response.extend_from_slice(&codings.join(&b", "[..]));
+                    response.extend_from_slice(HTTP_BR_BYTES);
+                }
+                continue;
+            }
+            response.extend_from_slice(line);
+            response.extend_from_slice(HTTP_BR_BYTES);
+        }
+        response.extend_from_slice(&decoder.trailers);
+        response.extend_from_slice(HTTP_BR_BYTES);
+        response.extend_from_slice(&decoder.body);
+        response
+    }
+
     /// Calculates a new buffer capacity based on current capacity and needed size.
     ///
     /// This function determines an appropriate buffer size, typically doubling the current
@@ -329,3 +555,355 @@ impl SharedResponseHandler {
         }
     }
 }
+
+/// Default implementation for ChunkedDecoder.
+///
+/// # Returns
+///
+/// - `ChunkedDecoder` - A decoder waiting for the first chunk-size line.
+impl Default for ChunkedDecoder {
+    fn default() -> Self {
+        Self {
+            state: ChunkedState::Size,
+            line: Vec::new(),
+            body: Vec::new(),
+            trailers: Vec::new(),
+        }
+    }
+}
+
+impl ChunkedDecoder {
+    /// Feeds newly received bytes into the decoder.
+    ///
+    /// Chunk extensions are ignored and trailer fields are collected for later use.
+    /// Bytes following the terminating chunk are discarded.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The bytes read from the stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<bool, RequestError>` - Whether the whole body has been received, or an error
+    ///   if the chunked framing is malformed.
+    pub(crate) fn decode(&mut self, data: &[u8]) -> Result<bool, RequestError> {
+        let mut pos: usize = 0;
+        while pos < data.len() {
+            match self.state {
+                ChunkedState::Size => {
+                    if !self.take_line(data, &mut pos)? {
+                        break;
+                    }
+                    let size: usize = Self::parse_chunk_size(&self.line)?;
+                    self.line.clear();
+                    self.state = if size == 0 {
+                        ChunkedState::Trailer
+                    } else {
+                        ChunkedState::Data(size)
+                    };
+                }
+                ChunkedState::Data(remaining) => {
+                    let take: usize = remaining.min(data.len() - pos);
+                    self.body.extend_from_slice(&data[pos..pos + take]);
+                    pos += take;
+                    self.state = if take == remaining {
+                        ChunkedState::DataEnd
+                    } else {
+                        ChunkedState::Data(remaining - take)
+                    };
+                }
+                ChunkedState::DataEnd => {
+                    if !self.take_line(data, &mut pos)? {
+                        break;
+                    }
+                    if !self.line.is_empty() {
+                        return Err(RequestError::Request(
+                            "chunk data is not terminated by CRLF".to_string(),
+                        ));
+                    }
+                    self.state = ChunkedState::Size;
+                }
+                ChunkedState::Trailer => {
+                    if !self.take_line(data, &mut pos)? {
+                        break;
+                    }
+                    if self.line.is_empty() {
+                        self.state = ChunkedState::Done;
+                    } else {
+                        self.trailers.extend_from_slice(&self.line);
+                        self.trailers.extend_from_slice(HTTP_BR_BYTES);
+                        self.line.clear();
+                    }
+                }
+                ChunkedState::Done => break,
+            }
+        }
+        Ok(self.is_done())
+    }
+
+    /// Checks whether the terminating chunk and trailer section have been received.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether decoding is complete.
+    pub(crate) fn is_done(&self) -> bool {
+        self.state == ChunkedState::Done
+    }
+
+    /// Appends bytes to the pending line until a line feed is found.
+    ///
+    /// The line terminator, including an optional carriage return, is stripped.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The bytes read from the stream.
+    /// - `&mut usize` - The current read position, advanced past the consumed bytes.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<bool, RequestError>` - Whether a complete line is available, or an error if the
+    ///   line exceeds the allowed length.
+    fn take_line(&mut self, data: &[u8], pos: &mut usize) -> Result<bool, RequestError> {
+        let rest: &[u8] = &data[*pos..];
+        let (consumed, found) = match rest.iter().position(|&byte| byte == b'\n') {
+            Some(index) => (index + 1, true),
+            None => (rest.len(), false),
+        };
+        self.line.extend_from_slice(&rest[..consumed]);
+        *pos += consumed;
+        if self.line.len() > MAX_CHUNKED_LINE_LENGTH {
+            return Err(RequestError::Request(
+                "chunked line exceeds maximum length".to_string(),
+            ));
+        }
+        if found {
+            self.line.pop();
+            if self.line.last() == Some(&b'\r') {
+                self.line.pop();
+            }
+        }
+        Ok(found)
+    }
+
+    /// Parses a chunk-size line, ignoring any chunk extensions.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The chunk-size line without its terminator.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<usize, RequestError>` - The chunk size, or an error if it is not valid hex.
+    fn parse_chunk_size(line: &[u8]) -> Result<usize, RequestError> {
+        let size_bytes: &[u8] = line
+            .split(|&byte| byte == b';')
+            .next()
+            .unwrap_or_default()
+            .trim_ascii();
+        if size_bytes.is_empty() {
+            return Err(RequestError::Request("missing chunk size".to_string()));
+        }
+        let mut size: usize = 0;
+        for &byte in size_bytes {
+            let digit: usize = (byte as char).to_digit(16).ok_or_else(|| {
+                RequestError::Request(format!(
+                    "invalid chunk size: {}",
+                    String::from_utf8_lossy(size_bytes)
+                ))
+            })? as usize;
+            size = size
+                .checked_mul(16)
+                .and_then(|size| size.checked_add(digit))
+                .ok_or_else(|| RequestError::Request("chunk size overflow".to_string()))?;
+        }
+        Ok(size)
+    }
+}
+
+impl ResponseParser {
+    /// Creates a parser for the response to a request with the given method.
+    ///
+    /// # Arguments
+    ///
+    /// - `Method` - The method of the request.
+    /// - `usize` - The configured read buffer size.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseParser` - A parser waiting for the response headers.
+    pub(crate) fn new(method: Method, buffer_size: usize) -> Self {
+        Self {
+            method,
+            location_sign_key: format!("{}:", LOCATION.to_lowercase()).into_bytes(),
+            response_bytes: Vec::with_capacity(buffer_size.max(8192)),
+            headers_end_pos: None,
+            framing: ResponseFraming::UntilClose,
+            redirect_url: None,
+            chunked_decoder: None,
+            keep_alive: false,
+        }
+    }
+
+    /// Feeds bytes read from the stream into the parser.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The bytes read from the stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<bool, RequestError>` - Whether the final response is complete and reading can stop.
+    pub(crate) fn feed(&mut self, data: &[u8]) -> Result<bool, RequestError> {
+        if let Some(decoder) = self.chunked_decoder.as_mut() {
+            return decoder.decode(data);
+        }
+        let new_capacity: usize = SharedResponseHandler::calculate_buffer_capacity(
+            &self.response_bytes,
+            data.len(),
+            self.response_bytes.capacity(),
+        );
+        if new_capacity > 0 {
+            self.response_bytes
+                .reserve(new_capacity - self.response_bytes.capacity());
+        }
+        let old_len: usize = self.response_bytes.len();
+        self.response_bytes.extend_from_slice(data);
+        let headers_end_pos: usize = match self.headers_end_pos {
+            Some(headers_end_pos) => headers_end_pos,
+            None => match self.parse_headers(old_len.saturating_sub(3))? {
+                Some(headers_end_pos) => headers_end_pos,
+                None => return Ok(false),
+            },
+        };
+        match self.framing {
+            ResponseFraming::NoBody => {
+                self.response_bytes.truncate(headers_end_pos);
+                Ok(true)
+            }
+            ResponseFraming::ContentLength(content_length) => {
+                let total_expected_length: usize = headers_end_pos + content_length;
+                if self.response_bytes.len() >= total_expected_length {
+                    self.response_bytes.truncate(total_expected_length);
+                    return Ok(true);
+                }
+                Ok(false)
+            }
+            ResponseFraming::Chunked => {
+                let mut decoder: ChunkedDecoder = ChunkedDecoder::default();
+                let done: bool = decoder.decode(&self.response_bytes[headers_end_pos..])?;
+                self.response_bytes.truncate(headers_end_pos);
+                self.chunked_decoder = Some(decoder);
+                Ok(done)
+            }
+            ResponseFraming::UntilClose => Ok(false),
+        }
+    }
+
+    /// Looks for the end of the header section, skipping interim responses.
+    ///
+    /// # Arguments
+    ///
+    /// - `usize` - The position to start searching from.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<Option<usize>, RequestError>` - The end of the final header section, if received.
+    fn parse_headers(&mut self, search_start: usize) -> Result<Option<usize>, RequestError> {
+        let mut search_start: usize = search_start;
+        while let Some(pos) =
+            SharedResponseHandler::find_double_crlf(&self.response_bytes, search_start)
+        {
+            let headers_end_pos: usize = pos + 4;
+            let headers_bytes: &[u8] = &self.response_bytes[..headers_end_pos];
+            let status_code: usize = SharedResponseHandler::get_status_code(headers_bytes);
+            if SharedResponseHandler::is_interim_status(status_code) {
+                self.response_bytes.drain(..headers_end_pos);
+                search_start = 0;
+                continue;
+            }
+            SharedResponseHandler::parse_response_headers(
+                headers_bytes,
+                &self.location_sign_key,
+                &mut self.redirect_url,
+            )?;
+            self.framing = SharedResponseHandler::get_response_framing(
+                headers_bytes,
+                status_code,
+                &self.method,
+            );
+            self.keep_alive = SharedResponseHandler::is_keep_alive(headers_bytes, status_code);
+            self.headers_end_pos = Some(headers_end_pos);
+            return Ok(Some(headers_end_pos));
+        }
+        Ok(None)
+    }
+
+    /// Checks whether nothing of the response has been received yet.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the parser is still waiting for its first byte.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.headers_end_pos.is_none() && self.response_bytes.is_empty()
+    }
+
+    /// Checks whether the connection can carry another request after this response.
+    ///
+    /// The response must be complete, delimited without relying on the connection being
+    /// closed, and the server must not have asked for the connection to be closed.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the connection can be reused.
+    pub(crate) fn is_reusable(&self) -> bool {
+        let Some(headers_end_pos) = self.headers_end_pos else {
+            return false;
+        };
+        self.keep_alive
+            && match self.framing {
+                ResponseFraming::NoBody => true,
+                ResponseFraming::ContentLength(content_length) => {
+                    self.response_bytes.len() == headers_end_pos + content_length
+                }
+                ResponseFraming::Chunked => self
+                    .chunked_decoder
+                    .as_ref()
+                    .is_some_and(|decoder| decoder.is_done()),
+                ResponseFraming::UntilClose => false,
+            }
+    }
+
+    /// Finishes parsing once reading has stopped.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<(Vec<u8>, Option<Vec<u8>>), RequestError>` - The raw bytes of the final response
+    ///   with a decoded body, and the redirect URL if any, or an error if the headers never
+    ///   arrived or the body was cut short.
+    pub(crate) fn finish(self) -> Result<(Vec<u8>, Option<Vec<u8>>), RequestError> {
+        let Some(headers_end_pos) = self.headers_end_pos else {
+            return Err(RequestError::Request(
+                "connection closed before response headers were complete".to_string(),
+            ));
+        };
+        if let ResponseFraming::ContentLength(content_length) = self.framing
+            && self.response_bytes.len() < headers_end_pos + content_length
+        {
+            return Err(RequestError::Request(
+                "connection closed before response body was complete".to_string(),
+            ));
+        }
+        let response_bytes: Vec<u8> = match self.chunked_decoder {
+            Some(decoder) => {
+                if !decoder.is_done() {
+                    return Err(RequestError::Request(
+                        "connection closed before chunked body was complete".to_string(),
+                    ));
+                }
+                SharedResponseHandler::assemble_chunked_response(&self.response_bytes, decoder)
+            }
+            None => self.response_bytes,
+        };
+        Ok((response_bytes, self.redirect_url))
+    }
+}
@@ -1,4 +1,6 @@
+pub(crate) mod r#enum;
 pub(crate) mod r#impl;
 pub(crate) mod r#struct;
 
+pub(crate) use r#enum::*;
 pub(crate) use r#struct::*;
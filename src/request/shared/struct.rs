@@ -1,5 +1,47 @@
+use crate::*;
+
 /// A shared builder for constructing HTTP requests.
 pub(crate) struct SharedRequestBuilder;
 
 /// A shared handler for processing HTTP responses.
 pub(crate) struct SharedResponseHandler;
+
+/// Incremental decoder for `Transfer-Encoding: chunked` response bodies.
+///
+/// Bytes can be fed as they arrive from the stream; the decoded body and
+/// trailer fields are collected until the terminating chunk is seen.
+#[derive(Debug, Clone)]
+pub(crate) struct ChunkedDecoder {
+    /// Current parsing state.
+    pub(crate) state: ChunkedState,
+    /// Partially received size, data terminator or trailer line.
+    pub(crate) line: Vec<u8>,
+    /// Decoded body content.
+    pub(crate) body: Vec<u8>,
+    /// Raw trailer field lines, each terminated by CRLF.
+    pub(crate) trailers: Vec<u8>,
+}
+
+/// Incremental parser that collects one HTTP response from a byte stream.
+///
+/// Interim 1xx responses are skipped and the body is framed according to the
+/// response status, request method and message headers.
+#[derive(Debug, Clone)]
+pub(crate) struct ResponseParser {
+    /// Method of the request the response belongs to.
+    pub(crate) method: Method,
+    /// Lowercase `location:` pattern used to detect redirects.
+    pub(crate) location_sign_key: Vec<u8>,
+    /// Raw bytes of the final response received so far.
+    pub(crate) response_bytes: Vec<u8>,
+    /// End of the header section once it has been received.
+    pub(crate) headers_end_pos: Option<usize>,
+    /// How the body of the final response is delimited.
+    pub(crate) framing: ResponseFraming,
+    /// Redirect target found in the response headers.
+    pub(crate) redirect_url: Option<Vec<u8>>,
+    /// Decoder for chunked bodies.
+    pub(crate) chunked_decoder: Option<ChunkedDecoder>,
+    /// Whether the server allows the connection to be reused after this response.
+    pub(crate) keep_alive: bool,
+}
@@ -0,0 +1,16 @@
+/// Phase of a request that exceeded the configured timeout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TimeoutPhase {
+    /// Opening the TCP connection to the server or proxy.
+    Connect,
+    /// Performing the TLS handshake with the server or proxy.
+    TlsHandshake,
+    /// Negotiating the tunnel with an HTTP or SOCKS5 proxy.
+    Proxy,
+    /// Writing the request.
+    Write,
+    /// Reading the response.
+    Read,
+    /// The whole request, redirects included.
+    Total,
+}
@@ -0,0 +1,137 @@
+use crate::*;
+
+impl std::fmt::Display for TimeoutPhase {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let phase: &str = match self {
+            Self::Connect => "connect",
+            Self::TlsHandshake => "TLS handshake",
+            Self::Proxy => "proxy negotiation",
+            Self::Write => "request write",
+            Self::Read => "response read",
+            Self::Total => "request",
+        };
+        write!(f, "{}", phase)
+    }
+}
+
+impl std::fmt::Display for RequestTimeoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} timed out after {} ms",
+            self.phase,
+            self.timeout.as_millis()
+        )
+    }
+}
+
+impl std::error::Error for RequestTimeoutError {}
+
+impl RequestTimeoutError {
+    /// Creates a timeout error.
+    ///
+    /// # Arguments
+    ///
+    /// - `TimeoutPhase` - The phase that timed out.
+    /// - `Duration` - The timeout that was exceeded.
+    ///
+    /// # Returns
+    ///
+    /// - `RequestTimeoutError` - The error.
+    pub(crate) fn new(phase: TimeoutPhase, timeout: Duration) -> Self {
+        Self { phase, timeout }
+    }
+
+    /// Checks whether an I/O error was caused by a socket timeout.
+    ///
+    /// # Arguments
+    ///
+    /// - `ErrorKind` - The kind of the error.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the operation timed out.
+    pub(crate) fn is_timeout_kind(kind: ErrorKind) -> bool {
+        matches!(kind, ErrorKind::WouldBlock | ErrorKind::TimedOut)
+    }
+
+    /// Recovers the timeout carried by an I/O error.
+    ///
+    /// # Arguments
+    ///
+    /// - `&io::Error` - The error returned by a stream.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<RequestTimeoutError>` - The timeout, if the error was raised by a `TimeoutStream`.
+    pub(crate) fn from_io_error(err: &io::Error) -> Option<Self> {
+        err.get_ref()?.downcast_ref::<Self>().copied()
+    }
+
+    /// Gets the phase that timed out.
+    ///
+    /// # Returns
+    ///
+    /// - `TimeoutPhase` - The phase.
+    pub fn get_phase(&self) -> TimeoutPhase {
+        self.phase
+    }
+
+    /// Gets the timeout that was exceeded.
+    ///
+    /// # Returns
+    ///
+    /// - `Duration` - The timeout.
+    pub fn get_timeout(&self) -> Duration {
+        self.timeout
+    }
+}
+
+impl<'a, S: Read + Write + ?Sized> TimeoutStream<'a, S> {
+    /// Wraps a stream for one phase of a request.
+    ///
+    /// # Arguments
+    ///
+    /// - `&'a mut S` - The stream.
+    /// - `RequestTimeoutError` - The error reported when a read or write times out.
+    ///
+    /// # Returns
+    ///
+    /// - `TimeoutStream<'a, S>` - The wrapped stream.
+    pub(crate) fn new(inner: &'a mut S, error: RequestTimeoutError) -> Self {
+        Self { inner, error }
+    }
+
+    /// Replaces a socket timeout with the error of the phase.
+    ///
+    /// # Arguments
+    ///
+    /// - `io::Error` - The error returned by the stream.
+    ///
+    /// # Returns
+    ///
+    /// - `io::Error` - The timeout of the phase, or the original error.
+    fn map_error(&self, err: io::Error) -> io::Error {
+        if RequestTimeoutError::is_timeout_kind(err.kind()) {
+            io::Error::new(ErrorKind::TimedOut, self.error)
+        } else {
+            err
+        }
+    }
+}
+
+impl<S: Read + Write + ?Sized> Read for TimeoutStream<'_, S> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.inner.read(buf).map_err(|err| self.map_error(err))
+    }
+}
+
+impl<S: Read + Write + ?Sized> Write for TimeoutStream<'_, S> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf).map_err(|err| self.map_error(err))
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush().map_err(|err| self.map_error(err))
+    }
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+/// Error raised when a phase of a request exceeds the configured timeout.
+///
+/// Requests return it as `HttpRequestError::Timeout`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestTimeoutError {
+    /// Phase that timed out.
+    pub(crate) phase: TimeoutPhase,
+    /// Timeout that was exceeded.
+    pub(crate) timeout: Duration,
+}
+
+/// Stream reporting socket timeouts of a phase as a `RequestTimeoutError`.
+///
+/// Errors keep the `TimedOut` kind and carry the `RequestTimeoutError`, which the conversion into
+/// `HttpRequestError` recovers.
+pub(crate) struct TimeoutStream<'a, S: ?Sized> {
+    /// Stream the phase runs on.
+    pub(crate) inner: &'a mut S,
+    /// Error reported when a read or write times out.
+    pub(crate) error: RequestTimeoutError,
+}
@@ -1,12 +0,0 @@
-use crate::*;
-
-impl Default for Tmp {
-    fn default() -> Self {
-        Self {
-            visit_url: HashSet::new(),
-            root_cert: RootCertStore {
-                roots: TLS_SERVER_ROOTS.to_vec(),
-            },
-        }
-    }
-}
@@ -1,7 +1,8 @@
 use crate::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Tmp {
     pub visit_url: HashSet<String>,
-    pub root_cert: RootCertStore,
+    pub connection_info: ConnectionInfo,
+    pub deadline: Option<Instant>,
 }
@@ -0,0 +1,198 @@
+use crate::*;
+
+impl ServerCertVerifier for AcceptInvalidCertsVerifier {
+    fn verify_server_cert(
+        &self,
+        _end_entity: &CertificateDer<'_>,
+        _intermediates: &[CertificateDer<'_>],
+        _server_name: &ServerName<'_>,
+        _ocsp_response: &[u8],
+        _now: UnixTime,
+    ) -> Result<ServerCertVerified, TlsError> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.provider.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.provider
+            .signature_verification_algorithms
+            .supported_schemes()
+    }
+}
+
+impl PinnedCertVerifier {
+    /// Hashes data the way pins are written.
+    ///
+    /// # Arguments
+    ///
+    /// - `&[u8]` - The data to hash.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The base64 encoded SHA-256 hash.
+    pub(crate) fn sha256(data: &[u8]) -> String {
+        base64_encode(&Sha256::digest(data))
+    }
+
+    /// Hashes the subject public key info of a certificate.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CertificateDer` - The certificate.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The base64 encoded SHA-256 hash, or `None` if the certificate
+    ///   could not be parsed.
+    pub(crate) fn spki_sha256(cert: &CertificateDer<'_>) -> Option<String> {
+        EndEntityCert::try_from(cert)
+            .ok()
+            .map(|cert| Self::sha256(cert.subject_public_key_info().as_ref()))
+    }
+
+    /// Checks whether a certificate matches one of the pins.
+    ///
+    /// Only the leaf certificate is checked: the other certificates the server sends are not
+    /// necessarily part of the verified path, so anyone could append a pinned one.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CertificateDer` - The certificate.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the certificate or its public key is pinned.
+    fn is_pinned(&self, cert: &CertificateDer<'_>) -> bool {
+        self.cert_sha256.contains(&Self::sha256(cert.as_ref()))
+            || Self::spki_sha256(cert).is_some_and(|hash| self.spki_sha256.contains(&hash))
+    }
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> Result<ServerCertVerified, TlsError> {
+        let verified: ServerCertVerified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+        if self.is_pinned(end_entity) {
+            return Ok(verified);
+        }
+        let error: CertificatePinError = CertificatePinError {
+            server_name: server_name.to_str().into_owned(),
+            spki_sha256: Self::spki_sha256(end_entity).unwrap_or_default(),
+        };
+        Err(TlsError::InvalidCertificate(CertificateError::Other(
+            OtherError(Arc::new(error)),
+        )))
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, TlsError> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+impl std::fmt::Display for CertificatePinError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "certificate pin mismatch for {}: presented public key sha256//{}",
+            self.server_name, self.spki_sha256
+        )
+    }
+}
+
+impl std::error::Error for CertificatePinError {}
+
+impl CertificatePinError {
+    /// Finds a pin mismatch behind a failed TLS handshake.
+    ///
+    /// # Arguments
+    ///
+    /// - `&io::Error` - The error returned by the handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<&CertificatePinError>` - The pin mismatch, if that is why the handshake failed.
+    pub(crate) fn from_io_error(err: &io::Error) -> Option<&CertificatePinError> {
+        match err.get_ref()?.downcast_ref::<TlsError>()? {
+            TlsError::InvalidCertificate(CertificateError::Other(other)) => {
+                other.0.downcast_ref::<CertificatePinError>()
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the name of the server whose certificate matched no pin.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The server name.
+    pub fn get_server_name(&self) -> &str {
+        &self.server_name
+    }
+
+    /// Gets the hash of the public key the server presented, in the form accepted by
+    /// `pin_spki_sha256`.
+    ///
+    /// # Returns
+    ///
+    /// - `&str` - The base64 SHA-256 hash of the subject public key info.
+    pub fn get_spki_sha256(&self) -> &str {
+        &self.spki_sha256
+    }
+}
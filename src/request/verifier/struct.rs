@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Verifier that accepts any server certificate.
+///
+/// Handshake signatures are still checked, so a pinned certificate cannot be presented
+/// without its private key.
+#[derive(Debug)]
+pub(crate) struct AcceptInvalidCertsVerifier {
+    /// Provider whose algorithms check the handshake signatures.
+    pub(crate) provider: Arc<CryptoProvider>,
+}
+
+/// Verifier that requires the leaf certificate or its public key to be pinned once the inner
+/// policy accepted the chain.
+#[derive(Debug)]
+pub(crate) struct PinnedCertVerifier {
+    /// Policy that verifies the chain before the pins are checked.
+    pub(crate) inner: Arc<dyn ServerCertVerifier>,
+    /// Base64 SHA-256 hashes of pinned subject public key infos.
+    pub(crate) spki_sha256: Vec<String>,
+    /// Base64 SHA-256 hashes of pinned DER certificates.
+    pub(crate) cert_sha256: Vec<String>,
+}
+
+/// Error raised when the leaf certificate of the server matches no configured pin.
+///
+/// Requests return it as `HttpRequestError::CertificatePin`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CertificatePinError {
+    /// Name of the server that presented the chain.
+    pub(crate) server_name: String,
+    /// Base64 SHA-256 hash of the subject public key info of the presented leaf certificate.
+    pub(crate) spki_sha256: String,
+}
@@ -0,0 +1,136 @@
+use crate::*;
+
+impl ConnectionInfo {
+    /// Creates connection info from the addresses of a TCP connection.
+    ///
+    /// # Arguments
+    ///
+    /// - `std::io::Result<SocketAddr>` - The remote address.
+    /// - `std::io::Result<SocketAddr>` - The local address.
+    ///
+    /// # Returns
+    ///
+    /// - `ConnectionInfo` - The info without TLS or proxy details.
+    pub(crate) fn from_addrs(
+        peer_addr: std::io::Result<SocketAddr>,
+        local_addr: std::io::Result<SocketAddr>,
+    ) -> Self {
+        Self {
+            peer_addr: peer_addr.ok(),
+            local_addr: local_addr.ok(),
+            ..Self::default()
+        }
+    }
+
+    /// Records the parameters negotiated by a TLS handshake.
+    ///
+    /// # Arguments
+    ///
+    /// - `&CommonState` - The state of the TLS connection to the target.
+    pub(crate) fn set_tls(&mut self, state: &CommonState) {
+        self.tls_version = state.protocol_version().map(|version| {
+            version
+                .as_str()
+                .map_or_else(|| format!("{:?}", version), str::to_string)
+        });
+        self.cipher_suite = state.negotiated_cipher_suite().map(|cipher_suite| {
+            let suite: CipherSuite = cipher_suite.suite();
+            suite
+                .as_str()
+                .map_or_else(|| format!("{:?}", suite), str::to_string)
+        });
+        self.alpn_protocol = state
+            .alpn_protocol()
+            .map(|protocol| String::from_utf8_lossy(protocol).into_owned());
+        self.resumed = state.handshake_kind() == Some(HandshakeKind::Resumed);
+    }
+
+    /// Records the proxy the connection goes through.
+    ///
+    /// # Arguments
+    ///
+    /// - `&ProxyConfig` - The proxy configuration, whose credentials are left out.
+    pub(crate) fn set_proxy(&mut self, proxy_config: &ProxyConfig) {
+        let scheme: &str = match proxy_config.proxy_type {
+            ProxyType::Http => "http",
+            ProxyType::Https => "https",
+            ProxyType::Socks5 => "socks5",
+        };
+        self.proxy = Some(format!(
+            "{}://{}:{}",
+            scheme, proxy_config.host, proxy_config.port
+        ));
+    }
+
+    /// Gets the negotiated TLS protocol version.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The version, or `None` for cleartext connections.
+    pub fn get_tls_version(&self) -> Option<String> {
+        self.tls_version.clone()
+    }
+
+    /// Gets the negotiated TLS cipher suite.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The cipher suite, or `None` for cleartext connections.
+    pub fn get_cipher_suite(&self) -> Option<String> {
+        self.cipher_suite.clone()
+    }
+
+    /// Gets the protocol agreed through ALPN.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The protocol, or `None` when none was negotiated.
+    pub fn get_alpn_protocol(&self) -> Option<String> {
+        self.alpn_protocol.clone()
+    }
+
+    /// Gets the remote address of the TCP connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The address of the server, or of the proxy when one is used.
+    pub fn get_peer_addr(&self) -> Option<SocketAddr> {
+        self.peer_addr
+    }
+
+    /// Gets the local address of the TCP connection.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<SocketAddr>` - The local address.
+    pub fn get_local_addr(&self) -> Option<SocketAddr> {
+        self.local_addr
+    }
+
+    /// Gets the proxy the connection went through.
+    ///
+    /// # Returns
+    ///
+    /// - `Option<String>` - The proxy as `scheme://host:port`, or `None` for direct connections.
+    pub fn get_proxy(&self) -> Option<String> {
+        self.proxy.clone()
+    }
+
+    /// Checks whether the connection is encrypted with TLS.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether a TLS version was negotiated.
+    pub fn is_tls(&self) -> bool {
+        self.tls_version.is_some()
+    }
+
+    /// Checks whether the TLS handshake resumed an earlier session instead of a full handshake.
+    ///
+    /// # Returns
+    ///
+    /// - `bool` - Whether the session was resumed.
+    pub fn is_resumed(&self) -> bool {
+        self.resumed
+    }
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+/// Details of the connection a response was received on.
+///
+/// Fields are `None` when they do not apply, e.g. the TLS fields of a cleartext connection.
+/// Through a proxy, the peer address is the address of the proxy.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    /// Negotiated TLS protocol version, e.g. `TLSv1_3`.
+    pub(crate) tls_version: Option<String>,
+    /// Negotiated TLS cipher suite, e.g. `TLS13_AES_256_GCM_SHA384`.
+    pub(crate) cipher_suite: Option<String>,
+    /// Protocol agreed through ALPN, e.g. `h2`.
+    pub(crate) alpn_protocol: Option<String>,
+    /// Remote address of the TCP connection.
+    pub(crate) peer_addr: Option<SocketAddr>,
+    /// Local address of the TCP connection.
+    pub(crate) local_addr: Option<SocketAddr>,
+    /// Proxy the connection went through, as `scheme://host:port`.
+    pub(crate) proxy: Option<String>,
+    /// Whether the TLS handshake resumed an earlier session.
+    pub(crate) resumed: bool,
+}
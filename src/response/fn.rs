@@ -0,0 +1,37 @@
+use crate::*;
+
+/// Gets every value of a header, in the order received.
+///
+/// # Arguments
+///
+/// - `&ResponseHeaders` - The response headers.
+/// - `&str` - The header name, matched case-insensitively.
+///
+/// # Returns
+///
+/// - `ResponseHeadersValue` - All values of the header, empty if absent.
+pub(crate) fn get_header_values(headers: &ResponseHeaders, name: &str) -> ResponseHeadersValue {
+    headers
+        .iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .map(|(_, values)| values.clone())
+        .unwrap_or_default()
+}
+
+/// Gets the value of a header, joining repeated values with a comma.
+///
+/// # Arguments
+///
+/// - `&ResponseHeaders` - The response headers.
+/// - `&str` - The header name, matched case-insensitively.
+///
+/// # Returns
+///
+/// - `OptionResponseHeadersValueItem` - The comma-joined value, or None if absent.
+pub(crate) fn get_header(headers: &ResponseHeaders, name: &str) -> OptionResponseHeadersValueItem {
+    let values: ResponseHeadersValue = get_header_values(headers, name);
+    if values.is_empty() {
+        return None;
+    }
+    Some(Vec::from(values).join(", "))
+}
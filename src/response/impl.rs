@@ -0,0 +1,24 @@
+use crate::*;
+
+/// JSON decoding for boxed responses.
+impl dyn ResponseTrait<OutputText = HttpResponseText, OutputBinary = HttpResponseBinary> {
+    /// Parses the response body as a JSON value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<JsonValue, ResponseJsonError>` - The parsed JSON, or an error with the status code
+    ///   and a body snippet.
+    pub fn json_value(&self) -> Result<JsonValue, ResponseJsonError> {
+        self.binary().json_value()
+    }
+
+    /// Deserializes the response body into a typed value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, ResponseJsonError>` - The decoded value, or an error with the status code
+    ///   and a body snippet.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, ResponseJsonError> {
+        self.binary().json()
+    }
+}
@@ -0,0 +1,2 @@
+/// Maximum number of characters of the response body kept in a JSON decoding error.
+pub(crate) const JSON_ERROR_BODY_SNIPPET_LENGTH: usize = 256;
@@ -0,0 +1,69 @@
+use crate::*;
+
+impl std::fmt::Display for ResponseJsonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "JSON decode error (status {}): {}; body: {}",
+            self.status_code, self.message, self.body_snippet
+        )
+    }
+}
+
+impl std::error::Error for ResponseJsonError {}
+
+impl ResponseJsonError {
+    /// Creates a JSON decoding error for a response.
+    ///
+    /// # Arguments
+    ///
+    /// - `ResponseStatusCode` - The response status code.
+    /// - `&[u8]` - The response body.
+    /// - `T` - The decoding failure (must implement ToString).
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseJsonError` - The error with a truncated body snippet.
+    pub(crate) fn new<T: ToString>(status_code: ResponseStatusCode, body: &[u8], error: T) -> Self {
+        let body_text: Cow<'_, str> = String::from_utf8_lossy(body);
+        let mut body_snippet: String = body_text
+            .chars()
+            .take(JSON_ERROR_BODY_SNIPPET_LENGTH)
+            .collect();
+        if body_text.chars().count() > JSON_ERROR_BODY_SNIPPET_LENGTH {
+            body_snippet.push_str("...");
+        }
+        Self {
+            status_code,
+            body_snippet,
+            message: error.to_string(),
+        }
+    }
+
+    /// Gets the status code of the response that failed to decode.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseStatusCode` - The status code.
+    pub fn get_status_code(&self) -> ResponseStatusCode {
+        self.status_code
+    }
+
+    /// Gets the leading part of the response body.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The body snippet.
+    pub fn get_body_snippet(&self) -> String {
+        self.body_snippet.clone()
+    }
+
+    /// Gets the description of the decoding failure.
+    ///
+    /// # Returns
+    ///
+    /// - `String` - The error message.
+    pub fn get_message(&self) -> String {
+        self.message.clone()
+    }
+}
@@ -0,0 +1,6 @@
+pub(crate) mod r#const;
+pub(crate) mod r#impl;
+pub(crate) mod r#struct;
+
+pub(crate) use r#const::*;
+pub use r#struct::*;
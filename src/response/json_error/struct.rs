@@ -0,0 +1,15 @@
+use crate::*;
+
+/// Error returned when a response body cannot be decoded as JSON.
+///
+/// Carries the response status code and the start of the body so failed API
+/// calls can be diagnosed without keeping the whole response around.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResponseJsonError {
+    /// HTTP response status code.
+    pub(crate) status_code: ResponseStatusCode,
+    /// Leading part of the response body.
+    pub(crate) body_snippet: String,
+    /// Description of the decoding failure.
+    pub(crate) message: String,
+}
@@ -1,9 +1,17 @@
+pub(crate) mod connection_info;
+pub(crate) mod r#fn;
+pub(crate) mod r#impl;
+pub(crate) mod json_error;
 pub(crate) mod response_binary;
 pub(crate) mod response_text;
 pub(crate) mod r#trait;
 pub(crate) mod r#type;
 
+pub use connection_info::*;
+pub use json_error::*;
 pub use response_binary::*;
 pub use response_text::*;
 pub use r#trait::*;
 pub use r#type::*;
+
+pub(crate) use r#fn::*;
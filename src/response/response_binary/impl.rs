@@ -24,7 +24,12 @@ impl ResponseTrait for HttpResponseBinary {
     where
         Self: Sized,
     {
-        let split_lines: Vec<&[u8]> = split_multi_byte(response, HTTP_BR_BYTES);
+        let (head, body): (&[u8], &[u8]) =
+            match SharedResponseHandler::find_double_crlf(response, 0) {
+                Some(pos) => (&response[..pos], &response[pos + 4..]),
+                None => (response, &[]),
+            };
+        let split_lines: Vec<&[u8]> = split_multi_byte(head, HTTP_BR_BYTES);
         let mut lines: IntoIter<&[u8]> = split_lines.into_iter();
         let status_line: &[u8] = lines.next().unwrap_or(&[]);
         let status_parts: Vec<&[u8]> = split_whitespace(&status_line);
@@ -83,46 +88,31 @@ impl ResponseTrait for HttpResponseBinary {
                     if let (Ok(key_str), Ok(value_str)) =
                         (from_utf8(key_bytes), from_utf8(value_bytes))
                     {
-                        let mut value_deque: VecDeque<String> = VecDeque::new();
-                        value_deque.push_front(value_str.trim().to_string());
-                        headers.insert(key_str.trim().to_string(), value_deque);
+                        let key: &str = key_str.trim();
+                        let value: String = value_str.trim().to_string();
+                        match headers
+                            .iter_mut()
+                            .find(|(existing_key, _)| existing_key.eq_ignore_ascii_case(key))
+                        {
+                            Some((_, value_deque)) => value_deque.push_back(value),
+                            None => {
+                                let mut value_deque: VecDeque<String> = VecDeque::new();
+                                value_deque.push_back(value);
+                                headers.insert(key.to_string(), value_deque);
+                            }
+                        }
                     }
                 }
             }
         }
-        let body: Vec<u8> = match lines.len() {
-            0 => Vec::new(),
-            1 => {
-                let line: &[u8] = lines.next().unwrap_or(&[]);
-                let mut body = Vec::with_capacity(line.len());
-                body.extend_from_slice(line);
-                body
-            }
-            _ => {
-                let lines_slice: &[&[u8]] = lines.as_slice();
-                let total_size: usize = lines_slice
-                    .iter()
-                    .map(|line: &&[u8]| line.len())
-                    .sum::<usize>()
-                    + lines_slice.len().saturating_sub(1) * BR_BYTES.len();
-                let mut body: Vec<u8> = Vec::with_capacity(total_size);
-                let mut first: bool = true;
-                for line in lines {
-                    if !first {
-                        body.extend_from_slice(BR_BYTES);
-                    }
-                    body.extend_from_slice(line);
-                    first = false;
-                }
-                body
-            }
-        };
+        let body: Vec<u8> = body.to_vec();
         HttpResponseBinary {
             http_version: Arc::new(RwLock::new(http_version)),
             status_code,
             status_text: Arc::new(RwLock::new(status_text)),
             headers: Arc::new(RwLock::new(headers)),
             body: Arc::new(RwLock::new(body)),
+            connection_info: Arc::new(RwLock::new(ConnectionInfo::default())),
         }
     }
 
@@ -150,6 +140,7 @@ impl ResponseTrait for HttpResponseBinary {
             status_text: Arc::clone(&self.status_text),
             headers: Arc::clone(&self.headers),
             body: Arc::new(RwLock::new(body)),
+            connection_info: Arc::clone(&self.connection_info),
         }
     }
 
@@ -188,11 +179,34 @@ impl ResponseTrait for HttpResponseBinary {
             status_text: Arc::clone(&self.status_text),
             headers: Arc::clone(&self.headers),
             body: Arc::new(RwLock::new(decoded_body)),
+            connection_info: Arc::clone(&self.connection_info),
         }
     }
 }
 
 impl HttpResponseBinary {
+    /// Parses the response body as a JSON value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<JsonValue, ResponseJsonError>` - The parsed JSON, or an error with the status code
+    ///   and a body snippet.
+    pub fn json_value(&self) -> Result<JsonValue, ResponseJsonError> {
+        self.json()
+    }
+
+    /// Deserializes the response body into a typed value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, ResponseJsonError>` - The decoded value, or an error with the status code
+    ///   and a body snippet.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, ResponseJsonError> {
+        let body: RequestBody = self.get_body();
+        json_from_slice(&body)
+            .map_err(|error| ResponseJsonError::new(self.status_code, &body, error))
+    }
+
     /// Retrieves the HTTP version associated with this response.
     ///
     /// # Returns
@@ -257,6 +271,42 @@ impl HttpResponseBinary {
         return hash_map_xx_hash3_64();
     }
 
+    /// Gets every value of a response header, in the order received.
+    ///
+    /// Header names are matched case-insensitively, so repeated headers such as
+    /// `Set-Cookie` are all returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeadersValue` - All values of the header, empty if absent.
+    pub fn get_header_values(&self, name: &str) -> ResponseHeadersValue {
+        self.headers
+            .read()
+            .map_or(ResponseHeadersValue::new(), |headers| {
+                get_header_values(&headers, name)
+            })
+    }
+
+    /// Gets the value of a response header, joining repeated values with a comma.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `OptionResponseHeadersValueItem` - The comma-joined value, or None if absent.
+    pub fn get_header(&self, name: &str) -> OptionResponseHeadersValueItem {
+        self.headers
+            .read()
+            .ok()
+            .and_then(|headers| get_header(&headers, name))
+    }
+
     /// Retrieves the body content of the HTTP response.
     ///
     /// # Returns
@@ -272,6 +322,19 @@ impl HttpResponseBinary {
         }
         return RequestBody::new();
     }
+
+    /// Retrieves details of the connection the response was received on.
+    ///
+    /// # Returns
+    ///
+    /// - `ConnectionInfo` - The TLS parameters, addresses and proxy of the connection.
+    pub fn get_connection_info(&self) -> ConnectionInfo {
+        self.connection_info
+            .read()
+            .map_or(ConnectionInfo::default(), |connection_info| {
+                connection_info.clone()
+            })
+    }
 }
 
 /// Default implementation for HttpResponseBinary.
@@ -287,6 +350,7 @@ impl Default for HttpResponseBinary {
             status_text: Arc::new(RwLock::new(HttpStatus::Unknown.to_string())),
             headers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
             body: Arc::new(RwLock::new(Vec::new())),
+            connection_info: Arc::new(RwLock::new(ConnectionInfo::default())),
         }
     }
 }
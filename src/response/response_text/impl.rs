@@ -54,6 +54,7 @@ impl ResponseTrait for HttpResponseText {
             status_text: self.status_text.clone(),
             headers: self.headers.clone(),
             body: Arc::new(RwLock::new(body)),
+            connection_info: self.connection_info.clone(),
         }
     }
 
@@ -95,11 +96,34 @@ impl ResponseTrait for HttpResponseText {
             status_text: http_response.status_text,
             headers: http_response.headers,
             body: Arc::new(RwLock::new(body)),
+            connection_info: http_response.connection_info,
         }
     }
 }
 
 impl HttpResponseText {
+    /// Parses the response body as a JSON value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<JsonValue, ResponseJsonError>` - The parsed JSON, or an error with the status code
+    ///   and a body snippet.
+    pub fn json_value(&self) -> Result<JsonValue, ResponseJsonError> {
+        self.json()
+    }
+
+    /// Deserializes the response body into a typed value.
+    ///
+    /// # Returns
+    ///
+    /// - `Result<T, ResponseJsonError>` - The decoded value, or an error with the status code
+    ///   and a body snippet.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, ResponseJsonError> {
+        let body: RequestBodyString = self.get_body();
+        json_from_str(&body)
+            .map_err(|error| ResponseJsonError::new(self.status_code, body.as_bytes(), error))
+    }
+
     /// Retrieves the HTTP version associated with this response.
     ///
     /// # Returns
@@ -164,6 +188,42 @@ impl HttpResponseText {
         return hash_map_xx_hash3_64();
     }
 
+    /// Gets every value of a response header, in the order received.
+    ///
+    /// Header names are matched case-insensitively, so repeated headers such as
+    /// `Set-Cookie` are all returned.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name.
+    ///
+    /// # Returns
+    ///
+    /// - `ResponseHeadersValue` - All values of the header, empty if absent.
+    pub fn get_header_values(&self, name: &str) -> ResponseHeadersValue {
+        self.headers
+            .read()
+            .map_or(ResponseHeadersValue::new(), |headers| {
+                get_header_values(&headers, name)
+            })
+    }
+
+    /// Gets the value of a response header, joining repeated values with a comma.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The header name, matched case-insensitively.
+    ///
+    /// # Returns
+    ///
+    /// - `OptionResponseHeadersValueItem` - The comma-joined value, or None if absent.
+    pub fn get_header(&self, name: &str) -> OptionResponseHeadersValueItem {
+        self.headers
+            .read()
+            .ok()
+            .and_then(|headers| get_header(&headers, name))
+    }
+
     /// Retrieves the body content of the HTTP response as a `String`.
     ///
     /// This method attempts to read the body of the response. If the body can be successfully read,
@@ -183,6 +243,19 @@ impl HttpResponseText {
         }
         return RequestBodyString::new();
     }
+
+    /// Retrieves details of the connection the response was received on.
+    ///
+    /// # Returns
+    ///
+    /// - `ConnectionInfo` - The TLS parameters, addresses and proxy of the connection.
+    pub fn get_connection_info(&self) -> ConnectionInfo {
+        self.connection_info
+            .read()
+            .map_or(ConnectionInfo::default(), |connection_info| {
+                connection_info.clone()
+            })
+    }
 }
 
 /// Default implementation for HttpResponseText.
@@ -198,6 +271,7 @@ impl Default for HttpResponseText {
             status_text: Arc::new(RwLock::new(HttpStatus::Unknown.to_string())),
             headers: Arc::new(RwLock::new(hash_map_xx_hash3_64())),
             body: Arc::new(RwLock::new(String::new())),
+            connection_info: Arc::new(RwLock::new(ConnectionInfo::default())),
         }
     }
 }
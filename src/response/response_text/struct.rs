@@ -13,6 +13,7 @@ use crate::*;
 /// - `headers`: A `HashMap<String, String>` containing the headers of the response, where each key is the header name
 ///   (e.g., "Content-Type"), and the value is the corresponding header value.
 /// - `body`: A `Vec<u8>` representing the body of the HTTP response, which contains the content being returned.
+/// - `connection_info`: Details of the connection the response was received on.
 #[derive(Debug, Clone)]
 pub struct HttpResponseText {
     /// HTTP protocol version.
@@ -25,4 +26,6 @@ pub struct HttpResponseText {
     pub(crate) headers: ArcRwLock<ResponseHeaders>,
     /// HTTP response body content.
     pub(crate) body: ArcRwLock<RequestBodyString>,
+    /// Connection the response was received on.
+    pub(crate) connection_info: ArcRwLock<ConnectionInfo>,
 }
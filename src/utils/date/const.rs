@@ -0,0 +1,10 @@
+/// Abbreviated month names used in HTTP dates, starting with January.
+pub(crate) const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Abbreviated weekday names used in HTTP dates, starting with Thursday 1970-01-01.
+pub(crate) const WEEKDAY_NAMES: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+
+/// Number of seconds in a day.
+pub(crate) const SECONDS_PER_DAY: u64 = 86_400;
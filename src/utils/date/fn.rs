@@ -0,0 +1,186 @@
+use crate::*;
+
+/// Converts a civil date to the number of days since 1970-01-01.
+///
+/// # Arguments
+///
+/// - `i64` - The year.
+/// - `u32` - The month, from 1 to 12.
+/// - `u32` - The day of the month, from 1 to 31.
+///
+/// # Returns
+///
+/// - `i64` - The number of days since the Unix epoch.
+pub(crate) fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year: i64 = if month <= 2 { year - 1 } else { year };
+    let era: i64 = year.div_euclid(400);
+    let year_of_era: i64 = year - era * 400;
+    let month_index: i64 = (month as i64 + 9) % 12;
+    let day_of_year: i64 = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era: i64 = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Converts a number of days since 1970-01-01 to a civil date.
+///
+/// # Arguments
+///
+/// - `i64` - The number of days since the Unix epoch.
+///
+/// # Returns
+///
+/// - `(i64, u32, u32)` - The year, month and day of the month.
+pub(crate) fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days: i64 = days + 719_468;
+    let era: i64 = days.div_euclid(146_097);
+    let day_of_era: i64 = days - era * 146_097;
+    let year_of_era: i64 =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year: i64 = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index: i64 = (5 * day_of_year + 2) / 153;
+    let day: u32 = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month: u32 = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    } as u32;
+    let year: i64 = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Parses a leading run of one to `max` ASCII digits.
+///
+/// # Arguments
+///
+/// - `&str` - The token to parse.
+/// - `usize` - The maximum number of digits.
+///
+/// # Returns
+///
+/// - `Option<(u32, &str)>` - The parsed number and the remaining text.
+fn parse_date_digits(token: &str, max: usize) -> Option<(u32, &str)> {
+    let digits: usize = token
+        .bytes()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if digits == 0 || digits > max {
+        return None;
+    }
+    let (number, rest) = token.split_at(digits);
+    number.parse::<u32>().ok().map(|number| (number, rest))
+}
+
+/// Parses a date in any format accepted by the RFC 6265 cookie-date algorithm.
+///
+/// # Arguments
+///
+/// - `&str` - The date, for example `Wed, 21 Oct 2015 07:28:00 GMT`.
+///
+/// # Returns
+///
+/// - `Option<SystemTime>` - The parsed time, or None if the date is invalid.
+pub(crate) fn parse_cookie_date(input: &str) -> Option<SystemTime> {
+    let is_delimiter = |ch: char| {
+        ch == '\t'
+            || (' '..='/').contains(&ch)
+            || (';'..='@').contains(&ch)
+            || ('['..='`').contains(&ch)
+            || ('{'..='~').contains(&ch)
+    };
+    let mut time: Option<(u32, u32, u32)> = None;
+    let mut day_of_month: Option<u32> = None;
+    let mut month: Option<u32> = None;
+    let mut year: Option<u32> = None;
+    for token in input.split(is_delimiter).filter(|token| !token.is_empty()) {
+        if time.is_none()
+            && let Some((hour, rest)) = parse_date_digits(token, 2)
+            && let Some((minute, rest)) = rest
+                .strip_prefix(':')
+                .and_then(|rest| parse_date_digits(rest, 2))
+            && let Some((second, rest)) = rest
+                .strip_prefix(':')
+                .and_then(|rest| parse_date_digits(rest, 2))
+            && !rest.starts_with(|ch: char| ch.is_ascii_digit())
+        {
+            time = Some((hour, minute, second));
+            continue;
+        }
+        if day_of_month.is_none()
+            && let Some((day, rest)) = parse_date_digits(token, 2)
+            && !rest.starts_with(|ch: char| ch.is_ascii_digit())
+        {
+            day_of_month = Some(day);
+            continue;
+        }
+        if month.is_none()
+            && let Some(index) = MONTH_NAMES.iter().position(|name| {
+                token
+                    .get(..3)
+                    .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+            })
+        {
+            month = Some(index as u32 + 1);
+            continue;
+        }
+        if year.is_none()
+            && let Some((value, rest)) = parse_date_digits(token, 4)
+            && value >= 10
+            && !rest.starts_with(|ch: char| ch.is_ascii_digit())
+        {
+            year = Some(value);
+        }
+    }
+    let (hour, minute, second) = time?;
+    let day: u32 = day_of_month?;
+    let month: u32 = month?;
+    let mut year: u32 = year?;
+    if (70..=99).contains(&year) {
+        year += 1900;
+    } else if year <= 69 {
+        year += 2000;
+    }
+    if !(1..=31).contains(&day) || year < 1601 || hour > 23 || minute > 59 || second > 59 {
+        return None;
+    }
+    let days: i64 = days_from_civil(year as i64, month, day);
+    if civil_from_days(days) != (year as i64, month, day) {
+        return None;
+    }
+    let seconds: i64 = days * SECONDS_PER_DAY as i64 + (hour * 3600 + minute * 60 + second) as i64;
+    if seconds >= 0 {
+        UNIX_EPOCH.checked_add(Duration::from_secs(seconds as u64))
+    } else {
+        UNIX_EPOCH.checked_sub(Duration::from_secs(seconds.unsigned_abs()))
+    }
+}
+
+/// Formats a time as an IMF-fixdate, for example `Wed, 21 Oct 2015 07:28:00 GMT`.
+///
+/// Times before the Unix epoch are formatted as the epoch.
+///
+/// # Arguments
+///
+/// - `SystemTime` - The time to format.
+///
+/// # Returns
+///
+/// - `String` - The formatted date.
+pub(crate) fn format_http_date(time: SystemTime) -> String {
+    let seconds: u64 = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let days: u64 = seconds / SECONDS_PER_DAY;
+    let seconds_of_day: u64 = seconds % SECONDS_PER_DAY;
+    let (year, month, day) = civil_from_days(days as i64);
+    format!(
+        "{}, {:02} {} {:04} {:02}:{:02}:{:02} GMT",
+        WEEKDAY_NAMES[(days % 7) as usize],
+        day,
+        MONTH_NAMES[month as usize - 1],
+        year,
+        seconds_of_day / 3600,
+        seconds_of_day % 3600 / 60,
+        seconds_of_day % 60
+    )
+}
@@ -3,3 +3,6 @@
 /// Contains the 64 characters used in standard base64 encoding:
 /// A-Z, a-z, 0-9, '+', and '/'.
 pub(crate) const CHARS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Uppercase hexadecimal digits used for percent-encoding.
+pub(crate) const HEX_UPPER: &[u8] = b"0123456789ABCDEF";
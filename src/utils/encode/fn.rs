@@ -32,3 +32,78 @@ pub(crate) fn base64_encode(input: &[u8]) -> String {
     }
     result
 }
+
+/// Percent-encodes a string for use in a query string or urlencoded form.
+///
+/// Unreserved characters (`A-Z`, `a-z`, `0-9`, `-`, `.`, `_`, `~`) are kept, every other
+/// UTF-8 byte is written as `%XX`.
+///
+/// # Arguments
+///
+/// - `&str` - The string to encode.
+///
+/// # Returns
+///
+/// - `String` - The percent-encoded string.
+pub(crate) fn percent_encode(input: &str) -> String {
+    let mut result: String = String::with_capacity(input.len());
+    for &byte in input.as_bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            result.push(byte as char);
+        } else {
+            result.push('%');
+            result.push(HEX_UPPER[(byte >> 4) as usize] as char);
+            result.push(HEX_UPPER[(byte & 15) as usize] as char);
+        }
+    }
+    result
+}
+
+/// Encodes key-value pairs as `key=value` joined with `&`.
+///
+/// # Arguments
+///
+/// - `&[(K, V)]` - The pairs to encode.
+///
+/// # Returns
+///
+/// - `String` - The percent-encoded pairs.
+pub(crate) fn encode_pairs<K: ToString, V: ToString>(pairs: &[(K, V)]) -> String {
+    pairs
+        .iter()
+        .map(|(key, value)| {
+            format!(
+                "{}={}",
+                percent_encode(&key.to_string()),
+                percent_encode(&value.to_string())
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("&")
+}
+
+/// Appends encoded query pairs to a URL, keeping any existing query and fragment.
+///
+/// # Arguments
+///
+/// - `&str` - The URL.
+/// - `&str` - The encoded query pairs.
+///
+/// # Returns
+///
+/// - `String` - The URL with the pairs merged into its query.
+pub(crate) fn append_query(url: &str, query: &str) -> String {
+    if query.is_empty() {
+        return url.to_owned();
+    }
+    let (base, fragment): (&str, &str) = match url.find('#') {
+        Some(pos) => url.split_at(pos),
+        None => (url, ""),
+    };
+    let separator: &str = match base.find('?') {
+        Some(pos) if pos + 1 == base.len() || base.ends_with('&') => "",
+        Some(_) => "&",
+        None => "?",
+    };
+    format!("{}{}{}{}", base, separator, query, fragment)
+}
@@ -1,5 +1,9 @@
+pub(crate) mod date;
 pub(crate) mod encode;
+pub(crate) mod url;
 pub(crate) mod vec;
 
+pub(crate) use date::*;
 pub(crate) use encode::*;
+pub(crate) use url::*;
 pub(crate) use vec::*;
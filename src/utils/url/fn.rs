@@ -0,0 +1,97 @@
+/// Removes `.` and `..` segments from a URL path (RFC 3986 section 5.2.4).
+///
+/// # Arguments
+///
+/// - `&str` - The path, starting with `/`.
+///
+/// # Returns
+///
+/// - `String` - The normalized path.
+pub(crate) fn remove_dot_segments(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    let parts: Vec<&str> = path.split('/').skip(1).collect();
+    for (index, segment) in parts.iter().enumerate() {
+        let is_last: bool = index + 1 == parts.len();
+        match *segment {
+            "." => {
+                if is_last {
+                    segments.push("");
+                }
+            }
+            ".." => {
+                segments.pop();
+                if is_last {
+                    segments.push("");
+                }
+            }
+            _ => segments.push(segment),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+/// Resolves a possibly relative URL reference against an absolute base URL.
+///
+/// Absolute references are returned unchanged. Scheme-relative, absolute-path,
+/// query-only and relative-path references are resolved as in RFC 3986 section 5.
+///
+/// # Arguments
+///
+/// - `&str` - The absolute base URL.
+/// - `&str` - The reference, for example a `Location` header value.
+///
+/// # Returns
+///
+/// - `String` - The absolute URL.
+pub(crate) fn resolve_url(base: &str, reference: &str) -> String {
+    let reference: &str = reference.trim();
+    let scheme_end: Option<usize> = reference.find(':');
+    if let Some(pos) = scheme_end
+        && pos > 0
+        && reference[..pos]
+            .bytes()
+            .all(|byte| byte.is_ascii_alphanumeric() || matches!(byte, b'+' | b'-' | b'.'))
+        && reference.as_bytes()[0].is_ascii_alphabetic()
+    {
+        return reference.to_owned();
+    }
+    let Some(scheme_pos) = base.find("://") else {
+        return reference.to_owned();
+    };
+    let authority_start: usize = scheme_pos + 3;
+    let authority_end: usize = base[authority_start..]
+        .find(['/', '?', '#'])
+        .map_or(base.len(), |pos| authority_start + pos);
+    let origin: &str = &base[..authority_end];
+    let base_without_fragment: &str = base.split('#').next().unwrap_or(base);
+    let base_path_and_query: &str =
+        &base_without_fragment[authority_end.min(base_without_fragment.len())..];
+    let base_path: &str = base_path_and_query
+        .split('?')
+        .next()
+        .filter(|path| !path.is_empty())
+        .unwrap_or("/");
+    if reference.starts_with("//") {
+        return format!("{}{}", &base[..scheme_pos + 1], reference);
+    }
+    if reference.is_empty() {
+        return base_without_fragment.to_owned();
+    }
+    if reference.starts_with('#') {
+        return format!("{}{}", base_without_fragment, reference);
+    }
+    if reference.starts_with('?') {
+        return format!("{}{}{}", origin, base_path, reference);
+    }
+    let (reference_path, suffix): (&str, &str) = match reference.find(['?', '#']) {
+        Some(pos) => reference.split_at(pos),
+        None => (reference, ""),
+    };
+    let merged_path: String = if reference_path.starts_with('/') {
+        reference_path.to_owned()
+    } else {
+        let directory: &str = &base_path[..base_path.rfind('/').map_or(0, |pos| pos + 1)];
+        format!("{}{}", directory, reference_path)
+    };
+    format!("{}{}{}", origin, remove_dot_segments(&merged_path), suffix)
+}
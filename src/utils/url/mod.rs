@@ -0,0 +1,3 @@
+pub(crate) mod r#fn;
+
+pub(crate) use r#fn::*;
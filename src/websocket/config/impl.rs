@@ -8,6 +8,9 @@ impl Default for WebSocketConfig {
             buffer: DEFAULT_BUFFER_SIZE,
             protocols: Vec::new(),
             proxy: None,
+            tls: TlsConfig::default(),
+            low_speed_limit: None,
+            dns: DnsConfig::default(),
         }
     }
 }
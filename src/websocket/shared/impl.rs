@@ -9,6 +9,10 @@ impl std::fmt::Display for WebSocketError {
             WebSocketErrorKind::InvalidUrl => write!(f, "Invalid URL: {}", self.message),
             WebSocketErrorKind::Io => write!(f, "IO error: {}", self.message),
             WebSocketErrorKind::Tls => write!(f, "TLS error: {}", self.message),
+            WebSocketErrorKind::CertificatePin => {
+                write!(f, "Certificate pin error: {}", self.message)
+            }
+            WebSocketErrorKind::LowSpeed => write!(f, "Low speed error: {}", self.message),
         }
     }
 }
@@ -57,6 +61,31 @@ impl WebSocketError {
             message: message.to_string(),
         }
     }
+
+    pub(crate) fn certificate_pin<T: ToString>(message: T) -> Self {
+        Self {
+            kind: WebSocketErrorKind::CertificatePin,
+            message: message.to_string(),
+        }
+    }
+
+    pub(crate) fn low_speed<T: ToString>(message: T) -> Self {
+        Self {
+            kind: WebSocketErrorKind::LowSpeed,
+            message: message.to_string(),
+        }
+    }
+
+    pub(crate) fn handshake(err: io::Error) -> Self {
+        match CertificatePinError::from_io_error(&err) {
+            Some(pin_error) => Self::certificate_pin(pin_error),
+            None => Self::tls(err),
+        }
+    }
+
+    pub fn get_kind(&self) -> &WebSocketErrorKind {
+        &self.kind
+    }
 }
 
 impl SharedWebSocketBuilder {
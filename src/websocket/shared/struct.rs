@@ -17,4 +17,6 @@ pub enum WebSocketErrorKind {
     InvalidUrl,
     Io,
     Tls,
+    CertificatePin,
+    LowSpeed,
 }
@@ -18,13 +18,39 @@ impl WebSocket {
         base64_encode(&key_bytes)
     }
 
-    fn get_headers(&self) -> Vec<(String, String)> {
-        let mut headers: Vec<(String, String)> = Vec::new();
-        for (key, value) in self.header.iter() {
-            if let Some(first_value) = value.front() {
-                headers.push((key.clone(), first_value.clone()));
+    /// Gets the headers of the opening handshake.
+    ///
+    /// User headers replace the handshake defaults of the same name instead of repeating them,
+    /// and the protocols set on the builder replace a user `Sec-WebSocket-Protocol` header.
+    ///
+    /// # Arguments
+    ///
+    /// - `&str` - The target host.
+    /// - `u16` - The target port.
+    /// - `&[String]` - The requested subprotocols.
+    ///
+    /// # Returns
+    ///
+    /// - `OrderedHeaders` - The handshake headers in the order they are sent.
+    fn get_handshake_headers(&self, host: &str, port: u16, protocols: &[String]) -> OrderedHeaders {
+        let mut headers: OrderedHeaders = OrderedHeaders::default();
+        headers.set(HOST.to_string(), format!("{}:{}", host, port));
+        headers.set(UPGRADE.to_string(), "websocket".to_string());
+        headers.set(CONNECTION.to_string(), "Upgrade".to_string());
+        headers.set(SEC_WEBSOCKET_VERSION.to_string(), "13".to_string());
+        headers.set(
+            SEC_WEBSOCKET_KEY.to_string(),
+            Self::generate_websocket_key(),
+        );
+        for (key, values) in &self.header.entries {
+            match headers.get_mut(key) {
+                Some(existing) => *existing = values.clone(),
+                None => headers.entries.push((key.clone(), values.clone())),
             }
         }
+        if !protocols.is_empty() {
+            headers.set(SEC_WEBSOCKET_PROTOCOL.to_string(), protocols.join(", "));
+        }
         headers
     }
 
@@ -37,6 +63,8 @@ impl WebSocket {
             return Err(WebSocketError::invalid_url("URL is empty"));
         }
         let url_obj: HttpUrlComponents = SharedWebSocketBuilder::parse_url(&url)?;
+        let target_host: String = url_obj.host.clone().unwrap_or_default();
+        let target_port: u16 = url_obj.port.unwrap_or_default();
         if let Ok(mut config) = self.config.write() {
             config.url_obj = url_obj;
         }
@@ -46,9 +74,15 @@ impl WebSocket {
                 .map(|c| c.timeout)
                 .unwrap_or(DEFAULT_TIMEOUT),
         );
-        let headers: Vec<(String, String)> = self.get_headers();
+        let protocols: Vec<String> = self
+            .config
+            .read()
+            .map(|c| c.protocols.clone())
+            .unwrap_or_default();
+        let headers: OrderedHeaders =
+            self.get_handshake_headers(&target_host, target_port, &protocols);
         let mut request_builder = Request::builder().uri(&url);
-        for (key, value) in &headers {
+        for (key, value) in headers.lines() {
             request_builder = request_builder.header(key, value);
         }
         let request: Request = request_builder
@@ -60,83 +94,48 @@ impl WebSocket {
             .ok()
             .and_then(|config| config.proxy.clone());
         let ws_stream: WebSocketConnectionType = if let Some(proxy_config) = proxy_config {
-            let url_obj: HttpUrlComponents = self
-                .config
-                .read()
-                .map(|c| c.url_obj.clone())
-                .unwrap_or_default();
-            let target_host: String = url_obj.host.clone().unwrap_or_default();
-            let target_port: u16 = url_obj.port.unwrap_or_default();
             let proxy_stream: BoxAsyncReadWrite = self
                 .get_proxy_connection_stream_async(target_host.clone(), target_port, &proxy_config)
                 .await?;
+            let proxy_stream: BoxAsyncReadWrite = if self.is_secure() {
+                let tls_stream: TlsStream<BoxAsyncReadWrite> = self
+                    .connect_tls_async(
+                        proxy_stream,
+                        self.get_tls_server_name(&target_host)?,
+                        self.get_tls_config()?,
+                    )
+                    .await?;
+                Box::new(tls_stream)
+            } else {
+                proxy_stream
+            };
             let proxy_tunnel_stream: WebSocketProxyTunnelStream =
                 WebSocketProxyTunnelStream::new(proxy_stream);
-            let mut proxy_request_builder = Request::builder().uri(&url);
-            proxy_request_builder = proxy_request_builder
-                .header(HOST, format!("{}:{}", target_host, target_port))
-                .header(UPGRADE, "websocket")
-                .header(CONNECTION, "Upgrade")
-                .header(SEC_WEBSOCKET_VERSION, "13")
-                .header(SEC_WEBSOCKET_KEY, Self::generate_websocket_key());
-            for (key, value) in &headers {
-                proxy_request_builder = proxy_request_builder.header(key, value);
-            }
-            let protocols: Vec<String> = self
-                .config
-                .read()
-                .map(|c| c.protocols.clone())
-                .unwrap_or_default();
-            if !protocols.is_empty() {
-                proxy_request_builder =
-                    proxy_request_builder.header("Sec-WebSocket-Protocol", protocols.join(", "));
-            }
-            let proxy_request: Request = proxy_request_builder.body(()).map_err(|e| {
-                WebSocketError::invalid_url(format!("Failed to build proxy request: {}", e))
-            })?;
-            let connect_future = client_async_with_config(proxy_request, proxy_tunnel_stream, None);
+            let connect_future = client_async_with_config(request, proxy_tunnel_stream, None);
             let (ws_stream, _) = timeout(timeout_duration, connect_future)
                 .await
                 .map_err(|_| WebSocketError::timeout("Connection timeout"))?
-                .map_err(|e| {
-                    let error_msg: String = e.to_string();
-                    if error_msg.contains("tls")
-                        || error_msg.contains("TLS")
-                        || error_msg.contains("ssl")
-                        || error_msg.contains("SSL")
-                        || error_msg.contains("certificate")
-                        || error_msg.contains("handshake")
-                    {
-                        WebSocketError::tls(error_msg)
-                    } else {
-                        WebSocketError::connection(error_msg)
-                    }
-                })?;
-            WebSocketConnectionType::Proxy(ws_stream)
+                .map_err(Self::get_connect_error)?;
+            WebSocketConnectionType::Proxy(Box::new(ws_stream))
         } else {
-            let connect_future = connect_async_with_config(request, None, false);
-            let (ws_stream, _) = timeout(timeout_duration, connect_future)
-                .await
-                .map_err(|_| WebSocketError::timeout("Connection timeout"))?
-                .map_err(|e| {
-                    let error_msg: String = e.to_string();
-                    if error_msg.contains("tls")
-                        || error_msg.contains("TLS")
-                        || error_msg.contains("ssl")
-                        || error_msg.contains("SSL")
-                        || error_msg.contains("certificate")
-                        || error_msg.contains("handshake")
-                    {
-                        WebSocketError::tls(error_msg)
-                    } else {
-                        WebSocketError::connection(error_msg)
-                    }
-                })?;
-            WebSocketConnectionType::Direct(ws_stream)
+            let connect_future = self.connect_direct_async(request, &target_host, target_port);
+            let ws_stream: WebSocketStream<MaybeTlsStream<AsyncTcpStream>> =
+                timeout(timeout_duration, connect_future)
+                    .await
+                    .map_err(|_| WebSocketError::timeout("Connection timeout"))??;
+            WebSocketConnectionType::Direct(Box::new(ws_stream))
         };
         let mut connection: AsyncMutexGuard<'_, Option<WebSocketConnectionType>> =
             self.connection.lock().await;
         *connection = Some(ws_stream);
+        if let Ok(mut low_speed) = self.low_speed.lock() {
+            *low_speed = self
+                .config
+                .read()
+                .ok()
+                .and_then(|config| config.low_speed_limit)
+                .map(LowSpeedTracker::new);
+        }
         self.connected.store(true, Ordering::Relaxed);
         Ok(())
     }
@@ -173,22 +172,55 @@ impl WebSocket {
                 .map(|c| c.timeout)
                 .unwrap_or(DEFAULT_TIMEOUT),
         );
+        let deadline: Instant = Instant::now() + timeout_duration;
         let mut connection: AsyncMutexGuard<'_, Option<WebSocketConnectionType>> =
             self.connection.lock().await;
         if let Some(ref mut ws_stream) = *connection {
-            let receive_future = ws_stream.next();
-            if let Some(msg_result) = timeout(timeout_duration, receive_future)
-                .await
-                .map_err(|_| WebSocketError::timeout("Receive timeout"))?
-            {
-                let message: Message =
-                    msg_result.map_err(|e| WebSocketError::protocol(e.to_string()))?;
-                return Ok(self.convert_message(message));
+            loop {
+                let remaining: Duration = deadline.saturating_duration_since(Instant::now());
+                let wait: Duration = self
+                    .get_low_speed_window()
+                    .map_or(remaining, |window| window.min(remaining));
+                let started: Instant = Instant::now();
+                let next: Result<Option<Result<Message, TungsteniteError>>, Elapsed> =
+                    timeout(wait, ws_stream.next()).await;
+                let received: usize = match &next {
+                    Ok(Some(Ok(message))) => message.len(),
+                    _ => 0,
+                };
+                self.record_low_speed(received, started.elapsed())?;
+                match next {
+                    Ok(Some(msg_result)) => {
+                        let message: Message =
+                            msg_result.map_err(|e| WebSocketError::protocol(e.to_string()))?;
+                        return Ok(self.convert_message(message));
+                    }
+                    Ok(None) => break,
+                    Err(_) if wait < remaining => continue,
+                    Err(_) => return Err(WebSocketError::timeout("Receive timeout")),
+                }
             }
         }
         Err(WebSocketError::connection("Connection closed"))
     }
 
+    fn get_low_speed_window(&self) -> Option<Duration> {
+        self.low_speed
+            .lock()
+            .ok()?
+            .as_ref()
+            .map(LowSpeedTracker::get_remaining)
+    }
+
+    fn record_low_speed(&self, bytes: usize, waited: Duration) -> Result<(), WebSocketError> {
+        if let Ok(mut low_speed) = self.low_speed.lock()
+            && let Some(tracker) = low_speed.as_mut()
+        {
+            tracker.record(bytes, waited)?;
+        }
+        Ok(())
+    }
+
     fn receive_message_sync(&self) -> Result<WebSocketMessage, WebSocketError> {
         let rt: Runtime = Runtime::new().map_err(|e| WebSocketError::io(e.to_string()))?;
         rt.block_on(self.receive_message_async())
@@ -229,6 +261,106 @@ impl WebSocket {
         rt.block_on(self.close_async_internal())
     }
 
+    fn get_connect_error(err: TungsteniteError) -> WebSocketError {
+        if let TungsteniteError::Io(io_err) = &err
+            && let Some(pin_error) = CertificatePinError::from_io_error(io_err)
+        {
+            return WebSocketError::certificate_pin(pin_error);
+        }
+        let error_msg: String = err.to_string();
+        if error_msg.contains("tls")
+            || error_msg.contains("TLS")
+            || error_msg.contains("ssl")
+            || error_msg.contains("SSL")
+            || error_msg.contains("certificate")
+            || error_msg.contains("handshake")
+        {
+            WebSocketError::tls(error_msg)
+        } else {
+            WebSocketError::connection(error_msg)
+        }
+    }
+
+    fn is_secure(&self) -> bool {
+        self.config
+            .read()
+            .is_ok_and(|config| config.url_obj.protocol.is_https())
+    }
+
+    fn get_tls_server_name(&self, host: &str) -> Result<ServerName<'static>, WebSocketError> {
+        self.config
+            .read()
+            .map_err(|err| WebSocketError::tls(err.to_string()))?
+            .tls
+            .get_server_name(host)
+            .map_err(|err| WebSocketError::tls(err.to_string()))
+    }
+
+    async fn connect_tls_async<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        stream: S,
+        server_name: ServerName<'static>,
+        tls_config: ClientConfig,
+    ) -> Result<TlsStream<S>, WebSocketError> {
+        TlsConnector::from(Arc::new(tls_config))
+            .connect(server_name, stream)
+            .await
+            .map_err(WebSocketError::handshake)
+    }
+
+    async fn connect_direct_async(
+        &self,
+        request: Request,
+        host: &str,
+        port: u16,
+    ) -> Result<WebSocketStream<MaybeTlsStream<AsyncTcpStream>>, WebSocketError> {
+        let tcp_stream: AsyncTcpStream = self
+            .get_dns_config()
+            .connect_async(host, port)
+            .await
+            .map_err(|err| WebSocketError::connection(err.to_string()))?;
+        let stream: MaybeTlsStream<AsyncTcpStream> = if self.is_secure() {
+            MaybeTlsStream::Rustls(
+                self.connect_tls_async(
+                    tcp_stream,
+                    self.get_tls_server_name(host)?,
+                    self.get_tls_config()?,
+                )
+                .await?,
+            )
+        } else {
+            MaybeTlsStream::Plain(tcp_stream)
+        };
+        let (ws_stream, _) = client_async_with_config(request, stream, None)
+            .await
+            .map_err(Self::get_connect_error)?;
+        Ok(ws_stream)
+    }
+
+    fn get_dns_config(&self) -> DnsConfig {
+        self.config
+            .read()
+            .map_or(DnsConfig::default(), |config| config.dns.clone())
+    }
+
+    fn get_tls_config(&self) -> Result<ClientConfig, WebSocketError> {
+        self.config
+            .read()
+            .map_err(|err| WebSocketError::tls(err.to_string()))?
+            .tls
+            .get_client_config()
+            .map_err(|err| WebSocketError::tls(err.to_string()))
+    }
+
+    fn get_proxy_tls_config(&self) -> Result<ClientConfig, WebSocketError> {
+        self.config
+            .read()
+            .map_err(|err| WebSocketError::tls(err.to_string()))?
+            .tls
+            .get_proxy_client_config()
+            .map_err(|err| WebSocketError::tls(err.to_string()))
+    }
+
     async fn get_proxy_connection_stream_async(
         &self,
         target_host: String,
@@ -253,24 +385,17 @@ impl WebSocket {
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, WebSocketError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(proxy_host_port)
+        let tcp_stream: AsyncTcpStream = self
+            .get_dns_config()
+            .connect_async(&proxy_config.host, proxy_config.port)
             .await
             .map_err(|err| WebSocketError::connection(err.to_string()))?;
         let mut proxy_stream: BoxAsyncReadWrite = if proxy_config.proxy_type == ProxyType::Https {
-            let roots: RootCertStore = RootCertStore {
-                roots: TLS_SERVER_ROOTS.iter().cloned().collect(),
-            };
-            let tls_config: ClientConfig = ClientConfig::builder()
-                .with_root_certificates(roots)
-                .with_no_client_auth();
-            let connector: TlsConnector = TlsConnector::from(Arc::new(tls_config));
-            let dns_name: ServerName<'_> = ServerName::try_from(proxy_config.host.clone())
-                .map_err(|err| WebSocketError::tls(err.to_string()))?;
-            let tls_stream: TlsStream<AsyncTcpStream> = connector
-                .connect(dns_name, tcp_stream)
-                .await
+            let server_name: ServerName<'static> = ServerName::try_from(proxy_config.host.clone())
                 .map_err(|err| WebSocketError::tls(err.to_string()))?;
+            let tls_stream: TlsStream<AsyncTcpStream> = self
+                .connect_tls_async(tcp_stream, server_name, self.get_proxy_tls_config()?)
+                .await?;
             Box::new(tls_stream)
         } else {
             Box::new(tcp_stream)
@@ -319,8 +444,9 @@ impl WebSocket {
         target_port: u16,
         proxy_config: &ProxyConfig,
     ) -> Result<BoxAsyncReadWrite, WebSocketError> {
-        let proxy_host_port: (String, u16) = (proxy_config.host.clone(), proxy_config.port);
-        let mut tcp_stream: AsyncTcpStream = AsyncTcpStream::connect(proxy_host_port)
+        let mut tcp_stream: AsyncTcpStream = self
+            .get_dns_config()
+            .connect_async(&proxy_config.host, proxy_config.port)
             .await
             .map_err(|err| WebSocketError::connection(err.to_string()))?;
         let auth_methods: Vec<u8> =
@@ -6,8 +6,8 @@ use crate::*;
 /// providing a unified interface for WebSocket operations.
 #[derive(Debug)]
 pub enum WebSocketConnectionType {
-    Direct(WebSocketStream<MaybeTlsStream<AsyncTcpStream>>),
-    Proxy(WebSocketStream<WebSocketProxyTunnelStream>),
+    Direct(Box<WebSocketStream<MaybeTlsStream<AsyncTcpStream>>>),
+    Proxy(Box<WebSocketStream<WebSocketProxyTunnelStream>>),
 }
 
 /// Represents a WebSocket client connection.
@@ -21,13 +21,15 @@ pub struct WebSocket {
     /// The WebSocket server URL.
     pub(crate) url: Arc<String>,
     /// HTTP headers for the WebSocket handshake.
-    pub(crate) header: Arc<RequestHeaders>,
+    pub(crate) header: Arc<OrderedHeaders>,
     /// Configuration settings for the WebSocket connection.
     pub(crate) config: ArcRwLock<WebSocketConfig>,
     /// Atomic flag indicating connection status.
     pub(crate) connected: Arc<AtomicBool>,
     /// The underlying WebSocket connection.
     pub(crate) connection: WebSocketConnection,
+    /// Speed measurement of received messages when a low-speed limit is configured.
+    pub(crate) low_speed: Arc<Mutex<Option<LowSpeedTracker>>>,
 }
 
 /// Clone implementation for WebSocket.
@@ -35,6 +37,7 @@ pub struct WebSocket {
 /// Creates a new WebSocket instance with cloned configuration but resets:
 /// - Connection status to false
 /// - Connection to None
+/// - Speed measurement to None
 impl Clone for WebSocket {
     fn clone(&self) -> Self {
         Self {
@@ -43,6 +46,7 @@ impl Clone for WebSocket {
             config: self.config.clone(),
             connected: Arc::new(AtomicBool::new(false)),
             connection: Arc::new(AsyncMutex::new(None)),
+            low_speed: Arc::new(Mutex::new(None)),
         }
     }
 }
@@ -59,10 +63,11 @@ impl Default for WebSocket {
     fn default() -> Self {
         Self {
             url: Arc::new(String::new()),
-            header: Arc::new(hash_map_xx_hash3_64()),
+            header: Arc::new(OrderedHeaders::default()),
             config: Arc::new(RwLock::new(WebSocketConfig::default())),
             connected: Arc::new(AtomicBool::new(false)),
             connection: Arc::new(AsyncMutex::new(None)),
+            low_speed: Arc::new(Mutex::new(None)),
         }
     }
 }
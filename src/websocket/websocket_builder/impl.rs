@@ -17,30 +17,33 @@ impl WebSocketBuilder {
     {
         if let Some(tmp_header) = Arc::get_mut(&mut self.websocket.header) {
             for (key, value) in header {
-                let key_str: String = key.to_string();
-                let value_str: String = value.to_string();
-                let mut found_existing: bool = false;
-                let mut existing_key: Option<String> = None;
-                for existing_key_ref in tmp_header.keys() {
-                    if existing_key_ref.eq_ignore_ascii_case(&key_str) {
-                        existing_key = Some(existing_key_ref.clone());
-                        found_existing = true;
-                        break;
-                    }
-                }
-                if found_existing {
-                    if let Some(existing_key) = existing_key {
-                        tmp_header.remove(&existing_key);
-                    }
-                }
-                let mut value_deque: VecDeque<String> = VecDeque::new();
-                value_deque.push_front(value_str);
-                tmp_header.insert(key_str, value_deque);
+                tmp_header.set(key.to_string(), value.to_string());
             }
         }
         self
     }
 
+    pub fn header<K: ToString, V: ToString>(&mut self, key: K, value: V) -> &mut Self {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.websocket.header) {
+            tmp_header.set(key.to_string(), value.to_string());
+        }
+        self
+    }
+
+    pub fn append_header<K: ToString, V: ToString>(&mut self, key: K, value: V) -> &mut Self {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.websocket.header) {
+            tmp_header.append(key.to_string(), value.to_string());
+        }
+        self
+    }
+
+    pub fn remove_header(&mut self, key: &str) -> &mut Self {
+        if let Some(tmp_header) = Arc::get_mut(&mut self.websocket.header) {
+            tmp_header.remove(key);
+        }
+        self
+    }
+
     pub fn timeout(&mut self, timeout: u64) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.timeout = timeout;
@@ -48,6 +51,13 @@ impl WebSocketBuilder {
         self
     }
 
+    pub fn low_speed_limit(&mut self, bytes_per_second: u64, time: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.low_speed_limit = Some(LowSpeedLimit::new(bytes_per_second, time));
+        }
+        self
+    }
+
     pub fn buffer(&mut self, buffer: usize) -> &mut Self {
         if let Ok(mut config) = self.websocket.config.write() {
             config.buffer = buffer;
@@ -158,6 +168,176 @@ impl WebSocketBuilder {
         self
     }
 
+    pub fn root_cert_pem(&mut self, pem: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().add_root_cert_pem(pem);
+        }
+        self
+    }
+
+    pub fn root_cert_der(&mut self, der: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().add_root_cert_der(der);
+        }
+        self
+    }
+
+    pub fn root_cert_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().add_root_cert_file(path.as_ref());
+        }
+        self
+    }
+
+    pub fn system_root_certs(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().add_system_root_certs();
+        }
+        self
+    }
+
+    pub fn without_webpki_roots(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().webpki_roots = false;
+        }
+        self
+    }
+
+    pub fn client_cert_pem(&mut self, cert_chain: &[u8], key: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config
+                .tls
+                .detach_cache()
+                .set_client_cert_pem(cert_chain, key);
+        }
+        self
+    }
+
+    pub fn client_cert_der(&mut self, cert: &[u8], key: &[u8]) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().set_client_cert_der(cert, key);
+        }
+        self
+    }
+
+    pub fn client_cert_file<P: AsRef<Path>>(&mut self, cert_path: P, key_path: P) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config
+                .tls
+                .detach_cache()
+                .set_client_cert_file(cert_path.as_ref(), key_path.as_ref());
+        }
+        self
+    }
+
+    pub fn danger_accept_invalid_certs(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().verification = CertVerification::AcceptInvalid;
+        }
+        self
+    }
+
+    pub fn cert_verifier(&mut self, verifier: Arc<dyn ServerCertVerifier>) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().verification = CertVerification::Custom(verifier);
+        }
+        self
+    }
+
+    pub fn pin_spki_sha256(&mut self, pin: &str) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().add_spki_pin(pin);
+        }
+        self
+    }
+
+    pub fn pin_cert_sha256(&mut self, pin: &str) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().add_cert_pin(pin);
+        }
+        self
+    }
+
+    pub fn tls_versions(&mut self, versions: &[&'static SupportedProtocolVersion]) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().versions = versions.to_vec();
+        }
+        self
+    }
+
+    pub fn tls1_3_only(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().versions = vec![&TLS13];
+        }
+        self
+    }
+
+    pub fn tls_cipher_suites(&mut self, cipher_suites: &[SupportedCipherSuite]) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().cipher_suites = cipher_suites.to_vec();
+        }
+        self
+    }
+
+    pub fn without_tls_sni(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().sni = false;
+        }
+        self
+    }
+
+    pub fn tls_server_name(&mut self, name: &str) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().set_server_name(name);
+        }
+        self
+    }
+
+    pub fn without_tls_session_resumption(&mut self) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().session_resumption = false;
+        }
+        self
+    }
+
+    pub fn tls_key_log_file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().set_key_log_file(path.as_ref());
+        }
+        self
+    }
+
+    pub fn tls_key_log(&mut self, key_log: Arc<dyn KeyLog>) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.tls.detach_cache().key_log = Some(TlsKeyLog(key_log));
+        }
+        self
+    }
+
+    pub fn resolve(&mut self, host: &str, port: u16, addr: IpAddr) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            StaticResolver::insert_entry(&mut config.dns.detach().overrides, host, port, addr);
+        }
+        self
+    }
+
+    pub fn resolver(&mut self, resolver: Arc<dyn Resolver>) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config.dns.detach().resolver = SharedResolver(resolver);
+        }
+        self
+    }
+
+    pub fn dns_cache(&mut self, ttl: u64) -> &mut Self {
+        if let Ok(mut config) = self.websocket.config.write() {
+            config
+                .dns
+                .detach()
+                .set_cache_ttl(Duration::from_millis(ttl));
+        }
+        self
+    }
+
     pub fn build_sync(&mut self) -> WebSocket {
         self.builder = self.websocket.clone();
         self.websocket = WebSocket::default();